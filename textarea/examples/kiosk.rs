@@ -0,0 +1,20 @@
+use std::time::Duration;
+
+use anyhow::Result;
+use textarea::{Outcome, Textarea};
+
+fn main() -> Result<()> {
+    let mut textarea: Textarea<String> = Textarea::new()?;
+    textarea
+        .with_prompt("> ")
+        .with_idle_callback(Duration::from_secs(5), |line| {
+            (!line.is_empty()).then(|| "Still there? Press enter to submit.".to_owned())
+        });
+
+    loop {
+        match textarea.interact_text_timeout(Duration::from_secs(15))? {
+            Outcome::Submitted(s) => println!("{s}"),
+            Outcome::TimedOut(partial) => println!("(timed out, discarding {partial:?})"),
+        }
+    }
+}
@@ -0,0 +1,11 @@
+use anyhow::Result;
+use textarea::Textarea;
+
+fn main() -> Result<()> {
+    let s: String = Textarea::new()?
+        .with_prompt("> ")
+        .with_shortcodes()
+        .interact_text()?;
+    println!("{s}");
+    Ok(())
+}
@@ -1,11 +1,14 @@
-use std::error::Error;
-
+use anyhow::Result;
 use textarea::Textarea;
 use universal_tagger::{LanguageDetector, Tagger};
 
-fn main() -> Result<(), Box<dyn Error>> {
+fn main() -> Result<()> {
+    let mut textarea = Textarea::new()?;
+    textarea
+        .with_prompt("> ")
+        .with_tagger(LanguageDetector::default());
     loop {
-        let text: String = Textarea::new()?.with_prompt("> ").interact_text()?;
+        let text: String = textarea.interact_text()?;
         if text == "quit" {
             break;
         }
@@ -0,0 +1,316 @@
+//! Bidirectional text, following [UAX #9](https://www.unicode.org/reports/tr9/).
+//!
+//! [`rustyline`] lays the line out in logical order and places the cursor accordingly,
+//! so lines holding right-to-left text are rendered in visual order by the highlighter,
+//! with the real cursor hidden and the grapheme at the cursor shown in reverse video.
+//! Each line takes its direction from its first strong character.
+
+use std::{
+    fmt::Write as _,
+    io::{self, IsTerminal, Write as _},
+    ops::Range,
+};
+
+use rustyline::{Cmd, KeyCode, KeyEvent, Modifiers};
+use unicode_bidi::{Level, ParagraphBidiInfo};
+use unicode_segmentation::UnicodeSegmentation;
+
+use crate::motion::motion;
+
+/// Escape sequence hiding the cursor.
+pub(crate) const HIDE_CURSOR: &str = "\x1b[?25l";
+/// Escape sequence showing the cursor.
+pub(crate) const SHOW_CURSOR: &str = "\x1b[?25h";
+
+/// Show the cursor again, once the line is read.
+pub(crate) fn show_cursor() {
+    let mut stdout = io::stdout();
+    if stdout.is_terminal() {
+        // NOTE: there is nothing better to do on failure
+        let _ = stdout.write_all(SHOW_CURSOR.as_bytes());
+        let _ = stdout.flush();
+    }
+}
+
+/// Whether `text` holds any right-to-left characters.
+#[must_use]
+pub(crate) fn has_rtl(text: &str) -> bool {
+    !text.is_ascii() && ParagraphBidiInfo::new(text, None).has_rtl()
+}
+
+/// A single line laid out in visual order.
+struct Layout {
+    /// Byte ranges of the graphemes from left to right, with whether they run right-to-left.
+    graphemes: Vec<(Range<usize>, bool)>,
+    /// Byte indices the cursor can be at, from left to right.
+    ///
+    /// The end of the line sits next to its last grapheme, on its left if it runs right-to-left.
+    slots: Vec<usize>,
+}
+
+impl Layout {
+    fn new(text: &str) -> Self {
+        let info = ParagraphBidiInfo::new(text, None);
+        let levels = info.reordered_levels(0..text.len());
+        let (ranges, levels): (Vec<_>, Vec<Level>) = text
+            .grapheme_indices(true)
+            .map(|(start, grapheme)| (start..start + grapheme.len(), levels[start]))
+            .unzip();
+
+        let graphemes: Vec<_> = ParagraphBidiInfo::reorder_visual(&levels)
+            .into_iter()
+            .map(|index| (ranges[index].clone(), levels[index].is_rtl()))
+            .collect();
+        let mut slots: Vec<_> = graphemes.iter().map(|(range, _)| range.start).collect();
+        let end = match graphemes
+            .iter()
+            .position(|(range, _)| range.end == text.len())
+        {
+            Some(last) if graphemes[last].1 => last,
+            Some(last) => last + 1,
+            None => 0,
+        };
+        slots.insert(end, text.len());
+        Self { graphemes, slots }
+    }
+
+    /// Whether the end of the line, `len`, is at its right, where the real cursor would be.
+    fn ends_on_the_right(&self, len: usize) -> bool {
+        self.slots.last() == Some(&len)
+    }
+
+    /// Write the line in visual order, marking the cursor at `pos`, if any.
+    ///
+    /// The SGR parameters of `styles`, by byte range of `text`, are kept on each grapheme.
+    fn render(
+        &self,
+        text: &str,
+        pos: Option<usize>,
+        styles: &[(Range<usize>, &str)],
+        rendered: &mut String,
+    ) {
+        // NOTE: the end of the line is marked on the last grapheme when the real cursor is elsewhere
+        let underlined = (pos == Some(text.len()) && !self.ends_on_the_right(text.len()))
+            .then(|| {
+                text.grapheme_indices(true)
+                    .next_back()
+                    .map(|(start, _)| start)
+            })
+            .flatten();
+        for (range, rtl) in &self.graphemes {
+            let grapheme = &text[range.clone()];
+            let grapheme = match grapheme.chars().next().and_then(mirror) {
+                Some(mirrored) if *rtl && grapheme.len() == 1 => {
+                    mirrored.encode_utf8(&mut [0; 4]).to_owned()
+                }
+                _ => grapheme.to_owned(),
+            };
+            let style = styles
+                .get(styles.partition_point(|(style, _)| style.end <= range.start))
+                .filter(|(style, _)| style.start <= range.start)
+                .map(|&(_, style)| style);
+            let mark = if pos == Some(range.start) {
+                Some("7")
+            } else if underlined == Some(range.start) {
+                Some("4")
+            } else {
+                None
+            };
+            match (style, mark) {
+                (Some(style), Some(mark)) => {
+                    let _ = write!(rendered, "\x1b[{style};{mark}m{grapheme}\x1b[0m");
+                }
+                (Some(style), None) | (None, Some(style)) => {
+                    let _ = write!(rendered, "\x1b[{style}m{grapheme}\x1b[0m");
+                }
+                (None, None) => rendered.push_str(&grapheme),
+            }
+        }
+    }
+}
+
+/// The mirrored glyph of `c`, for paired punctuation in right-to-left runs.
+const fn mirror(c: char) -> Option<char> {
+    Some(match c {
+        '(' => ')',
+        ')' => '(',
+        '[' => ']',
+        ']' => '[',
+        '{' => '}',
+        '}' => '{',
+        '<' => '>',
+        '>' => '<',
+        _ => return None,
+    })
+}
+
+/// The byte range of the line of `text` holding `pos`, without its line feed.
+fn line_at(text: &str, pos: usize) -> Range<usize> {
+    let start = text[..pos].rfind('\n').map_or(0, |i| i + 1);
+    let end = text[pos..].find('\n').map_or(text.len(), |i| pos + i);
+    start..end
+}
+
+/// Render `text` in visual order, if it holds any right-to-left characters.
+///
+/// The cursor at `pos`, if any, is drawn in place of the real one whenever they differ.
+/// The SGR parameters of `styles`, by byte range, are kept, as painted on text in logical order.
+#[must_use]
+pub(crate) fn reorder(
+    text: &str,
+    pos: Option<usize>,
+    styles: &[(Range<usize>, String)],
+) -> Option<String> {
+    if !has_rtl(text) {
+        return None;
+    }
+
+    let mut rendered = String::with_capacity(text.len() + HIDE_CURSOR.len());
+    let mut hidden = false;
+    let mut start = 0;
+    for line in text.split('\n') {
+        if start > 0 {
+            rendered.push('\n');
+        }
+        let layout = Layout::new(line);
+        let end = start + line.len();
+        let line_styles: Vec<_> = styles
+            .iter()
+            .filter(|(range, _)| start <= range.start && range.start < end)
+            .map(|(range, style)| {
+                (
+                    range.start - start..range.end.min(end) - start,
+                    style.as_str(),
+                )
+            })
+            .collect();
+        let pos = pos
+            .and_then(|pos| pos.checked_sub(start))
+            .filter(|&pos| pos <= line.len());
+        hidden |= pos.is_some_and(|pos| pos < line.len() || !layout.ends_on_the_right(line.len()));
+        layout.render(line, pos, &line_styles, &mut rendered);
+        start += line.len() + 1;
+    }
+    rendered.insert_str(0, if hidden { HIDE_CURSOR } else { SHOW_CURSOR });
+    Some(rendered)
+}
+
+/// Handle a key event, returning [`None`] if it should get its default behaviour.
+///
+/// On lines holding right-to-left characters, `left` and `right` move in visual order.
+/// At either edge of the line, they get their default behaviour,
+/// so that suggestions can still be accepted with `right`.
+pub(crate) fn handle(key: KeyEvent, line: &str, pos: usize) -> Option<Cmd> {
+    use KeyCode as K;
+    use Modifiers as M;
+
+    let right = match key {
+        KeyEvent(K::Right, M::NONE) => true,
+        KeyEvent(K::Left, M::NONE) => false,
+        _ => return None,
+    };
+    let range = line_at(line, pos);
+    let text = &line[range.clone()];
+    if !has_rtl(text) {
+        return None;
+    }
+
+    let slots = Layout::new(text).slots;
+    let current = slots.iter().position(|&slot| slot == pos - range.start)?;
+    let target = if right {
+        slots.get(current + 1)?
+    } else {
+        slots.get(current.checked_sub(1)?)?
+    };
+    Some(motion(line, pos, range.start + target, false))
+}
+
+#[cfg(test)]
+mod tests {
+    use rustyline::{CharSearch, Movement};
+
+    use super::*;
+
+    #[test]
+    fn visual_order() {
+        assert!(!has_rtl("hello"));
+        assert!(has_rtl("hello سلام"));
+        assert_eq!(reorder("hello", Some(0), &[]), None);
+
+        assert_eq!(
+            reorder("ab سلام", None, &[]).as_deref(),
+            Some("\x1b[?25hab مالس")
+        );
+        assert_eq!(
+            reorder("سلام (1)", None, &[]).as_deref(),
+            Some("\x1b[?25h(1) مالس")
+        );
+        assert_eq!(
+            reorder("ab سلام", Some(3), &[]).as_deref(),
+            Some("\x1b[?25lab مال\x1b[7mس\x1b[0m")
+        );
+        // NOTE: the end of a right-to-left line is at its left
+        assert_eq!(
+            reorder("سلام", Some("سلام".len()), &[]).as_deref(),
+            Some("\x1b[?25l\x1b[4mم\x1b[0mالس")
+        );
+        assert_eq!(
+            reorder("ab\nسلام", Some(1), &[]).as_deref(),
+            Some("\x1b[?25la\x1b[7mb\x1b[0m\nمالس")
+        );
+    }
+
+    #[test]
+    fn keep_styles() {
+        let styles = [(0..2, "2".to_owned()), (3..5, "33".to_owned())];
+        assert_eq!(
+            reorder("ab سلام", None, &styles).as_deref(),
+            Some("\x1b[?25h\x1b[2ma\x1b[0m\x1b[2mb\x1b[0m مال\x1b[33mس\x1b[0m")
+        );
+        assert_eq!(
+            reorder("ab سلام", Some(3), &styles).as_deref(),
+            Some("\x1b[?25l\x1b[2ma\x1b[0m\x1b[2mb\x1b[0m مال\x1b[33;7mس\x1b[0m")
+        );
+        assert_eq!(
+            reorder("ab\nسلام", None, &[(3..5, "4".to_owned())]).as_deref(),
+            Some("\x1b[?25hab\nمال\x1b[4mس\x1b[0m")
+        );
+    }
+
+    #[test]
+    fn visual_motions() {
+        let line = "ab سلام";
+        let start = "ab ".len();
+        assert_eq!(
+            handle(KeyEvent(KeyCode::Right, Modifiers::NONE), "ab", 0),
+            None
+        );
+        // NOTE: "س" is the rightmost grapheme, followed by nothing
+        assert_eq!(
+            handle(KeyEvent(KeyCode::Right, Modifiers::NONE), line, start),
+            None
+        );
+        assert_eq!(
+            handle(KeyEvent(KeyCode::Left, Modifiers::NONE), line, start),
+            Some(Cmd::Move(Movement::ViCharSearch(
+                1,
+                CharSearch::Forward('ل')
+            )))
+        );
+        assert_eq!(
+            handle(KeyEvent(KeyCode::Right, Modifiers::NONE), line, start + 2),
+            Some(Cmd::Move(Movement::ViCharSearch(
+                1,
+                CharSearch::Backward('س')
+            )))
+        );
+        // NOTE: the end of the line sits between "b " and "م"
+        assert_eq!(
+            handle(KeyEvent(KeyCode::Left, Modifiers::NONE), line, line.len()),
+            Some(Cmd::Move(Movement::ViCharSearch(
+                1,
+                CharSearch::Backward(' ')
+            )))
+        );
+    }
+}
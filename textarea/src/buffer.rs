@@ -0,0 +1,463 @@
+//! The editing semantics of [`Textarea`](crate::Textarea), without a terminal.
+
+use std::mem;
+
+use unicode_segmentation::UnicodeSegmentation;
+
+use crate::words::{next_word_end, previous_word_start};
+
+/// Kills kept at most in the kill ring.
+const KILL_RING_SIZE: usize = 60;
+
+/// A reversible change of the text.
+#[derive(Clone, Debug)]
+struct Change {
+    start: usize,
+    deleted: String,
+    inserted: String,
+    cursor: usize,
+}
+
+/// What the last command was, for commands depending on it.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum Last {
+    Other,
+    Insert,
+    Kill,
+    /// A yank, with the byte range of the yanked text and its index in the kill ring.
+    Yank {
+        start: usize,
+        end: usize,
+        index: usize,
+    },
+}
+
+/// A text being edited, with a cursor, an undo history and a kill ring.
+///
+/// This follows the emacs-like key bindings of [`Textarea`](crate::Textarea),
+/// but leaves input and rendering to the caller, so that it can be driven
+/// from any front-end. The cursor is a byte index, always at a grapheme boundary.
+#[derive(Clone, Debug)]
+pub struct TextBuffer {
+    text: String,
+    cursor: usize,
+    undos: Vec<Change>,
+    redos: Vec<Change>,
+    kill_ring: Vec<String>,
+    last: Last,
+}
+
+impl Default for TextBuffer {
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl From<String> for TextBuffer {
+    /// Start editing `text`, with the cursor at its end.
+    #[inline]
+    fn from(text: String) -> Self {
+        Self {
+            cursor: text.len(),
+            text,
+            ..Self::new()
+        }
+    }
+}
+
+impl From<&str> for TextBuffer {
+    /// Start editing `text`, with the cursor at its end.
+    #[inline]
+    fn from(text: &str) -> Self {
+        text.to_owned().into()
+    }
+}
+
+impl TextBuffer {
+    /// Create an empty [`TextBuffer`].
+    #[inline]
+    #[must_use]
+    pub const fn new() -> Self {
+        Self {
+            text: String::new(),
+            cursor: 0,
+            undos: Vec::new(),
+            redos: Vec::new(),
+            kill_ring: Vec::new(),
+            last: Last::Other,
+        }
+    }
+
+    /// The text being edited.
+    #[inline]
+    #[must_use]
+    pub fn text(&self) -> &str {
+        &self.text
+    }
+
+    /// The byte index of the cursor.
+    #[inline]
+    #[must_use]
+    pub const fn cursor(&self) -> usize {
+        self.cursor
+    }
+
+    /// Take the text out, leaving the buffer empty.
+    #[inline]
+    pub fn take(&mut self) -> String {
+        mem::take(self).text
+    }
+
+    /// Move the cursor to the grapheme boundary at or before `pos`.
+    pub fn set_cursor(&mut self, pos: usize) {
+        let pos = if pos < self.text.len() {
+            self.text
+                .grapheme_indices(true)
+                .map(|(start, _)| start)
+                .take_while(|&start| start <= pos)
+                .last()
+                .unwrap_or_default()
+        } else {
+            self.text.len()
+        };
+        self.move_to(pos);
+    }
+
+    /// Insert `text` at the cursor, moving the cursor after it.
+    pub fn insert(&mut self, text: &str) {
+        if text.is_empty() {
+            return;
+        }
+        // NOTE: typing is undone a word at once, along with the space before it
+        let merge = self.last == Last::Insert && !text.chars().all(char::is_whitespace);
+        self.apply(self.cursor..self.cursor, text, merge);
+        self.last = Last::Insert;
+    }
+
+    /// Insert a line feed at the cursor, as `alt+enter` does.
+    #[cfg(feature = "multiline")]
+    #[inline]
+    pub fn newline(&mut self) {
+        self.insert("\n");
+        self.last = Last::Other;
+    }
+
+    /// Delete `n` graphemes before the cursor, as `backspace` does.
+    pub fn delete_backward(&mut self, n: usize) {
+        let start = self.grapheme_before(self.cursor, n);
+        self.apply(start..self.cursor, "", false);
+        self.last = Last::Other;
+    }
+
+    /// Delete `n` graphemes after the cursor, as `delete` does.
+    pub fn delete_forward(&mut self, n: usize) {
+        let end = self.grapheme_after(self.cursor, n);
+        self.apply(self.cursor..end, "", false);
+        self.last = Last::Other;
+    }
+
+    /// Move the cursor `n` graphemes left, as `left` and `ctrl+b` do.
+    #[inline]
+    pub fn move_backward(&mut self, n: usize) {
+        self.move_to(self.grapheme_before(self.cursor, n));
+    }
+
+    /// Move the cursor `n` graphemes right, as `right` and `ctrl+f` do.
+    #[inline]
+    pub fn move_forward(&mut self, n: usize) {
+        self.move_to(self.grapheme_after(self.cursor, n));
+    }
+
+    /// Move the cursor to the end of the `n`th next word, as `alt+f` does.
+    #[inline]
+    pub fn move_word_forward(&mut self, n: usize) {
+        self.move_to(next_word_end(&self.text, self.cursor, n).unwrap_or(self.text.len()));
+    }
+
+    /// Move the cursor to the start of the `n`th previous word, as `alt+b` does.
+    #[inline]
+    pub fn move_word_backward(&mut self, n: usize) {
+        self.move_to(previous_word_start(&self.text, self.cursor, n).unwrap_or(0));
+    }
+
+    /// Move the cursor to the start of its line, as `ctrl+a` does.
+    #[inline]
+    pub fn move_line_start(&mut self) {
+        self.move_to(self.line_start());
+    }
+
+    /// Move the cursor to the end of its line, as `ctrl+e` does.
+    #[inline]
+    pub fn move_line_end(&mut self) {
+        self.move_to(self.line_end());
+    }
+
+    /// Move the cursor to the start of the text.
+    #[inline]
+    pub fn move_start(&mut self) {
+        self.move_to(0);
+    }
+
+    /// Move the cursor to the end of the text.
+    #[inline]
+    pub fn move_end(&mut self) {
+        self.move_to(self.text.len());
+    }
+
+    /// Kill up to the end of the `n`th next word, as `alt+d` does.
+    #[inline]
+    pub fn kill_word_forward(&mut self, n: usize) {
+        let end = next_word_end(&self.text, self.cursor, n).unwrap_or(self.text.len());
+        self.kill(self.cursor..end, true);
+    }
+
+    /// Kill up to the start of the `n`th previous word, as `ctrl+w` does.
+    #[inline]
+    pub fn kill_word_backward(&mut self, n: usize) {
+        let start = previous_word_start(&self.text, self.cursor, n).unwrap_or(0);
+        self.kill(start..self.cursor, false);
+    }
+
+    /// Kill up to the end of the line, as `ctrl+k` does.
+    #[inline]
+    pub fn kill_line_forward(&mut self) {
+        self.kill(self.cursor..self.line_end(), true);
+    }
+
+    /// Kill up to the start of the line, as `ctrl+u` does.
+    #[inline]
+    pub fn kill_line_backward(&mut self) {
+        self.kill(self.line_start()..self.cursor, false);
+    }
+
+    /// Insert the last killed text at the cursor, as `ctrl+y` does.
+    pub fn yank(&mut self) {
+        let Some(killed) = self.kill_ring.last().cloned() else {
+            return;
+        };
+        let start = self.cursor;
+        self.apply(start..start, &killed, false);
+        self.last = Last::Yank {
+            start,
+            end: self.cursor,
+            index: self.kill_ring.len() - 1,
+        };
+    }
+
+    /// Replace the text just yanked with the previous kill, as `alt+y` does.
+    pub fn yank_pop(&mut self) {
+        let Last::Yank { start, end, index } = self.last else {
+            return;
+        };
+        let index = index.checked_sub(1).unwrap_or(self.kill_ring.len() - 1);
+        let killed = self.kill_ring[index].clone();
+        self.apply(start..end, &killed, false);
+        self.last = Last::Yank {
+            start,
+            end: self.cursor,
+            index,
+        };
+    }
+
+    /// Undo the last change, returning whether there was any.
+    pub fn undo(&mut self) -> bool {
+        let Some(change) = self.undos.pop() else {
+            return false;
+        };
+        let end = change.start + change.inserted.len();
+        self.text.replace_range(change.start..end, &change.deleted);
+        self.cursor = change.cursor;
+        self.redos.push(change);
+        self.last = Last::Other;
+        true
+    }
+
+    /// Redo the last undone change, returning whether there was any.
+    pub fn redo(&mut self) -> bool {
+        let Some(change) = self.redos.pop() else {
+            return false;
+        };
+        let end = change.start + change.deleted.len();
+        self.text.replace_range(change.start..end, &change.inserted);
+        self.cursor = change.start + change.inserted.len();
+        self.undos.push(change);
+        self.last = Last::Other;
+        true
+    }
+
+    #[inline]
+    fn move_to(&mut self, pos: usize) {
+        self.cursor = pos;
+        self.last = Last::Other;
+    }
+
+    /// Replace `range` with `text`, leaving the cursor after it.
+    fn apply(&mut self, range: std::ops::Range<usize>, text: &str, merge: bool) {
+        if range.is_empty() && text.is_empty() {
+            return;
+        }
+        let deleted: String = self.text.drain(range.clone()).collect();
+        self.text.insert_str(range.start, text);
+        self.redos.clear();
+
+        match self.undos.last_mut() {
+            Some(last)
+                if merge
+                    && deleted.is_empty()
+                    && last.deleted.is_empty()
+                    && last.start + last.inserted.len() == range.start =>
+            {
+                last.inserted.push_str(text);
+            }
+            _ => self.undos.push(Change {
+                start: range.start,
+                deleted,
+                inserted: text.to_owned(),
+                cursor: self.cursor,
+            }),
+        }
+        self.cursor = range.start + text.len();
+    }
+
+    /// Remove `range` into the kill ring, appending to the last kill if consecutive.
+    fn kill(&mut self, range: std::ops::Range<usize>, forward: bool) {
+        if range.is_empty() {
+            return;
+        }
+        let killed = &self.text[range.clone()];
+        match (self.last, self.kill_ring.last_mut()) {
+            (Last::Kill, Some(last)) if forward => last.push_str(killed),
+            (Last::Kill, Some(last)) => last.insert_str(0, killed),
+            _ => {
+                if self.kill_ring.len() == KILL_RING_SIZE {
+                    self.kill_ring.remove(0);
+                }
+                self.kill_ring.push(killed.to_owned());
+            }
+        }
+        self.apply(range, "", false);
+        self.last = Last::Kill;
+    }
+
+    fn grapheme_before(&self, pos: usize, n: usize) -> usize {
+        self.text[..pos]
+            .grapheme_indices(true)
+            .rev()
+            .take(n)
+            .last()
+            .map_or(pos, |(start, _)| start)
+    }
+
+    fn grapheme_after(&self, pos: usize, n: usize) -> usize {
+        pos + self.text[pos..]
+            .graphemes(true)
+            .take(n)
+            .map(str::len)
+            .sum::<usize>()
+    }
+
+    fn line_start(&self) -> usize {
+        self.text[..self.cursor].rfind('\n').map_or(0, |i| i + 1)
+    }
+
+    fn line_end(&self) -> usize {
+        self.text[self.cursor..]
+            .find('\n')
+            .map_or(self.text.len(), |i| self.cursor + i)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn edit_and_undo() {
+        let mut buffer = TextBuffer::new();
+        for c in ["h", "e", "y", " ", "y", "o", "u"] {
+            buffer.insert(c);
+        }
+        assert_eq!(buffer.text(), "hey you");
+
+        buffer.move_backward(3);
+        buffer.delete_backward(1);
+        assert_eq!((buffer.text(), buffer.cursor()), ("heyyou", 3));
+        buffer.move_end();
+        buffer.insert("!");
+
+        assert!(buffer.undo());
+        assert!(buffer.undo());
+        assert_eq!((buffer.text(), buffer.cursor()), ("hey you", 4));
+        // NOTE: words are undone at once
+        assert!(buffer.undo());
+        assert_eq!((buffer.text(), buffer.cursor()), ("hey", 3));
+        assert!(buffer.undo());
+        assert_eq!(buffer.text(), "");
+        assert!(!buffer.undo());
+
+        assert!(buffer.redo());
+        assert!(buffer.redo());
+        assert_eq!((buffer.text(), buffer.cursor()), ("hey you", 7));
+        buffer.insert("x");
+        assert!(!buffer.redo());
+    }
+
+    #[test]
+    fn graphemes_and_lines() {
+        let mut buffer = TextBuffer::from("e\u{301}👍🏽\nabc");
+        buffer.move_line_start();
+        assert_eq!(buffer.cursor(), "e\u{301}👍🏽\n".len());
+        buffer.move_backward(2);
+        assert_eq!(buffer.cursor(), "e\u{301}".len());
+        buffer.delete_forward(1);
+        assert_eq!(buffer.text(), "e\u{301}\nabc");
+        buffer.move_line_end();
+        assert_eq!(buffer.cursor(), "e\u{301}".len());
+
+        buffer.set_cursor(1);
+        assert_eq!(buffer.cursor(), 0);
+        buffer.set_cursor(usize::MAX);
+        assert_eq!(buffer.cursor(), buffer.text().len());
+    }
+
+    #[test]
+    fn kill_and_yank() {
+        let mut buffer = TextBuffer::from("one two three");
+        buffer.kill_word_backward(1);
+        buffer.kill_word_backward(1);
+        assert_eq!(buffer.text(), "one ");
+
+        buffer.move_start();
+        buffer.kill_word_forward(1);
+        assert_eq!(buffer.text(), " ");
+
+        buffer.move_end();
+        buffer.yank();
+        assert_eq!(buffer.text(), " one");
+        buffer.yank_pop();
+        // NOTE: consecutive kills are joined
+        assert_eq!(buffer.text(), " two three");
+        buffer.yank_pop();
+        assert_eq!(buffer.text(), " one");
+
+        buffer.move_word_backward(1);
+        buffer.kill_line_forward();
+        buffer.kill_line_backward();
+        assert_eq!(buffer.text(), "");
+        assert!(buffer.undo());
+        assert_eq!(buffer.text(), " ");
+    }
+
+    #[cfg(feature = "multiline")]
+    #[test]
+    fn newlines() {
+        let mut buffer = TextBuffer::from("first");
+        buffer.newline();
+        buffer.insert("second");
+        assert_eq!(buffer.text(), "first\nsecond");
+        buffer.kill_line_backward();
+        assert_eq!(buffer.take(), "first\n");
+        assert_eq!(buffer.text(), "");
+    }
+}
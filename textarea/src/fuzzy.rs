@@ -0,0 +1,172 @@
+//! Fuzzy matching of history entries.
+
+use std::collections::HashSet;
+
+/// Score of every matched character.
+const SCORE_MATCH: i64 = 16;
+/// Bonus for a matched character right after another matched one.
+const BONUS_CONSECUTIVE: i64 = 8;
+/// Bonus for a matched character at the start of a word.
+const BONUS_BOUNDARY: i64 = 8;
+/// Penalty for every character skipped between two matched ones.
+const PENALTY_GAP: i64 = 1;
+/// Bonus given to the most recent entry, decreasing linearly with age.
+const BONUS_RECENCY: i64 = 16;
+
+/// A history entry matching a query.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Match {
+    /// Index of the entry in the history, the oldest being zero.
+    pub index: usize,
+    /// How well the entry matches, higher is better.
+    pub score: i64,
+    /// Char indices of the entry matched by the query.
+    pub positions: Vec<usize>,
+}
+
+/// Score `candidate` against `query` as a subsequence match.
+///
+/// Matching is case-insensitive unless `query` contains an uppercase character.
+/// This returns [`None`] if `query` is not a subsequence of `candidate`.
+#[must_use]
+pub fn score(query: &str, candidate: &str) -> Option<(i64, Vec<usize>)> {
+    let case_sensitive = query.chars().any(char::is_uppercase);
+    let eq = |q: char, c: char| {
+        if case_sensitive {
+            q == c
+        } else {
+            q.to_lowercase().eq(c.to_lowercase())
+        }
+    };
+
+    let query: Vec<_> = query.chars().filter(|c| !c.is_whitespace()).collect();
+    let chars: Vec<_> = candidate.chars().collect();
+    if query.is_empty() {
+        return Some((0, Vec::new()));
+    }
+
+    // Find the leftmost end of a match...
+    let mut end = None;
+    let mut q = 0;
+    for (i, &c) in chars.iter().enumerate() {
+        if eq(query[q], c) {
+            q += 1;
+            if q == query.len() {
+                end = Some(i);
+                break;
+            }
+        }
+    }
+    let end = end?;
+
+    // ...then walk backwards to find the tightest match ending there.
+    let mut positions = vec![0; query.len()];
+    let mut q = query.len();
+    for i in (0..=end).rev() {
+        if eq(query[q - 1], chars[i]) {
+            q -= 1;
+            positions[q] = i;
+            if q == 0 {
+                break;
+            }
+        }
+    }
+
+    let mut score = 0;
+    for (n, &i) in positions.iter().enumerate() {
+        score += SCORE_MATCH;
+        if i == 0 || !chars[i - 1].is_alphanumeric() {
+            score += BONUS_BOUNDARY;
+        }
+        if n > 0 {
+            let gap = i - positions[n - 1] - 1;
+            if gap == 0 {
+                score += BONUS_CONSECUTIVE;
+            } else {
+                score -= PENALTY_GAP * i64::try_from(gap).unwrap_or(i64::MAX);
+            }
+        }
+    }
+    Some((score, positions))
+}
+
+/// Rank history `entries`, ordered from oldest to newest, against `query`.
+///
+/// Repeated entries are only reported once, at their most recent index.
+/// Ties are broken in favour of the most recent entry.
+#[must_use]
+pub fn rank<S: AsRef<str>>(query: &str, entries: &[S]) -> Vec<Match> {
+    let len = i64::try_from(entries.len()).unwrap_or(i64::MAX);
+    let mut seen = HashSet::new();
+
+    let mut matches: Vec<_> = entries
+        .iter()
+        .enumerate()
+        .rev()
+        .map(|(index, entry)| (index, entry.as_ref()))
+        .filter(|&(_, entry)| seen.insert(entry))
+        .filter_map(|(index, entry)| {
+            let (score, positions) = score(query, entry)?;
+            let age = i64::try_from(index).unwrap_or(i64::MAX);
+            let recency = BONUS_RECENCY * (age + 1) / len;
+            Some(Match {
+                index,
+                score: score + recency,
+                positions,
+            })
+        })
+        .collect();
+    matches.sort_by(|a, b| b.score.cmp(&a.score).then(b.index.cmp(&a.index)));
+    matches
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn subsequence() {
+        assert!(score("gst", "git status").is_some());
+        assert!(score("gts", "git status").is_some());
+        assert!(score("gx", "git status").is_none());
+        assert_eq!(score("", "git status"), Some((0, Vec::new())));
+    }
+
+    #[test]
+    fn tightest_positions() {
+        let (_, positions) = score("st", "git status").unwrap();
+        assert_eq!(positions, [4, 5]);
+    }
+
+    #[test]
+    fn smart_case() {
+        assert!(score("GIT", "git status").is_none());
+        assert!(score("git", "GIT STATUS").is_some());
+    }
+
+    #[test]
+    fn consecutive_beats_scattered() {
+        let (tight, _) = score("status", "git status").unwrap();
+        let (loose, _) = score("status", "s t a t u s").unwrap();
+        assert!(tight > loose);
+    }
+
+    #[test]
+    fn ranking() {
+        let entries = ["cargo build", "cargo test", "git status", "cargo build"];
+        let matches = rank("cb", &entries);
+        assert_eq!(matches.len(), 1);
+        assert_eq!(matches[0].index, 3);
+
+        let matches = rank("", &entries);
+        let indices: Vec<_> = matches.iter().map(|m| m.index).collect();
+        assert_eq!(indices, [3, 2, 1]);
+    }
+
+    #[test]
+    fn multiline_entries() {
+        let entries = ["fn main() {\n    println!(\"hello\");\n}"];
+        let matches = rank("mainhello", &entries);
+        assert_eq!(matches.len(), 1);
+    }
+}
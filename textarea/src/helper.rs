@@ -0,0 +1,348 @@
+//! Glue between the features of [`Textarea`](crate::Textarea) and [`rustyline`].
+
+use std::{
+    borrow::Cow,
+    sync::{Arc, Mutex, MutexGuard},
+};
+
+use rustyline::{
+    completion::{Completer, Pair},
+    highlight::Highlighter,
+    hint::Hinter,
+    line_buffer::LineBuffer,
+    validate::Validator,
+    Changeset, ConditionalEventHandler, Context, Event, EventContext, RepeatCount,
+};
+
+use crate::{hints::Hints, history_search::Picker, timeout::Idle};
+
+/// State shared between the [`Helper`] and the [`Keymap`].
+#[derive(Default)]
+pub(crate) struct State {
+    pub(crate) picker: Picker,
+    pub(crate) hints: Hints,
+    pub(crate) idle: Idle,
+    #[cfg(feature = "shortcodes")]
+    pub(crate) shortcodes: bool,
+    #[cfg(feature = "tagger")]
+    pub(crate) tagging: Option<crate::tagging::Tagging>,
+    #[cfg(feature = "tagger")]
+    pub(crate) vocabulary: Option<crate::vocabulary::Vocabulary>,
+    #[cfg(feature = "spellcheck")]
+    pub(crate) spelling: Option<crate::spelling::Spelling>,
+    /// Whether the last line rendered hid the cursor.
+    #[cfg(feature = "bidi")]
+    pub(crate) cursor_hidden: bool,
+}
+
+#[derive(Clone, Default)]
+pub(crate) struct Shared(Arc<Mutex<State>>);
+
+impl Shared {
+    #[inline]
+    pub(crate) fn lock(&self) -> MutexGuard<'_, State> {
+        // NOTE: a panic while holding the lock leaves no invariant broken
+        self.0
+            .lock()
+            .unwrap_or_else(std::sync::PoisonError::into_inner)
+    }
+}
+
+/// Hints displayed after the cursor.
+pub(crate) enum Hint {
+    /// The history picker.
+    Picker(String),
+    /// A suggested completion, accepted with `right` or `ctrl+f`.
+    Suggestion(String),
+}
+
+impl rustyline::hint::Hint for Hint {
+    #[inline]
+    fn display(&self) -> &str {
+        match self {
+            Self::Picker(text) | Self::Suggestion(text) => text,
+        }
+    }
+
+    #[inline]
+    fn completion(&self) -> Option<&str> {
+        match self {
+            Self::Picker(_) => None,
+            Self::Suggestion(text) => Some(text),
+        }
+    }
+}
+
+/// [`rustyline::Helper`] for [`Textarea`](crate::Textarea).
+pub(crate) struct Helper {
+    state: Shared,
+}
+
+impl Helper {
+    #[inline]
+    pub(crate) const fn new(state: Shared) -> Self {
+        Self { state }
+    }
+}
+
+#[cfg(any(feature = "bidi", feature = "tagger"))]
+impl Helper {
+    /// The SGR parameters of `line` given by the tagger and the spell checker, if any, by byte range.
+    #[cfg(feature = "tagger")]
+    #[cfg_attr(not(feature = "spellcheck"), allow(unused_variables))]
+    fn styles(&self, line: &str, pos: usize) -> Vec<(std::ops::Range<usize>, String)> {
+        let state = self.state.lock();
+        let styles = state
+            .tagging
+            .as_ref()
+            .map_or_else(Vec::new, |tagging| tagging.styles(line));
+        #[cfg(feature = "spellcheck")]
+        let styles = match &state.spelling {
+            Some(spelling) => crate::tagging::underline(styles, spelling.misspelled(line, pos)),
+            None => styles,
+        };
+        styles
+    }
+
+    /// The SGR parameters of `line` by byte range, none without the tagger.
+    #[cfg(not(feature = "tagger"))]
+    fn styles(&self, _line: &str, _pos: usize) -> Vec<(std::ops::Range<usize>, String)> {
+        Vec::new()
+    }
+}
+
+impl Completer for Helper {
+    type Candidate = Pair;
+
+    #[cfg_attr(not(feature = "spellcheck"), allow(unused_mut))]
+    #[cfg_attr(
+        not(any(feature = "shortcodes", feature = "tagger")),
+        allow(unused_variables)
+    )]
+    fn complete(
+        &self,
+        line: &str,
+        pos: usize,
+        _ctx: &Context<'_>,
+    ) -> rustyline::Result<(usize, Vec<Self::Candidate>)> {
+        let mut state = self.state.lock();
+        #[cfg(feature = "spellcheck")]
+        if let Some(suggestions) = state
+            .spelling
+            .as_mut()
+            .and_then(|spelling| spelling.complete(line, pos))
+        {
+            return Ok(suggestions);
+        }
+        #[cfg(feature = "shortcodes")]
+        if state.shortcodes {
+            if let Some(completion) = crate::shortcodes::complete(line, pos) {
+                return Ok(completion);
+            }
+        }
+        #[cfg(feature = "tagger")]
+        if let Some(completion) = state
+            .vocabulary
+            .as_ref()
+            .and_then(|vocabulary| vocabulary.complete(line, pos))
+        {
+            return Ok(completion);
+        }
+        Ok((0, Vec::new()))
+    }
+
+    fn update(&self, line: &mut LineBuffer, start: usize, elected: &str, cl: &mut Changeset) {
+        #[cfg(feature = "spellcheck")]
+        let end = self
+            .state
+            .lock()
+            .spelling
+            .as_mut()
+            .and_then(crate::spelling::Spelling::take_word_end);
+        #[cfg(not(feature = "spellcheck"))]
+        let end = None;
+        // NOTE: suggestions replace the whole word, even after the cursor
+        let end = end
+            .filter(|&end| line.pos() <= end && end <= line.len())
+            .unwrap_or_else(|| line.pos());
+        line.replace(start..end, elected, cl);
+    }
+}
+
+impl Hinter for Helper {
+    type Hint = Hint;
+
+    fn hint(&self, line: &str, pos: usize, ctx: &Context<'_>) -> Option<Self::Hint> {
+        let mut state = self.state.lock();
+        state.idle.set_line(line);
+        #[cfg(feature = "tagger")]
+        if let Some(tagging) = &mut state.tagging {
+            tagging.update(line);
+        }
+        #[cfg(feature = "spellcheck")]
+        if let Some(spelling) = &mut state.spelling {
+            spelling.update(line);
+        }
+        if state.picker.is_active() {
+            return Some(Hint::Picker(state.picker.render(ctx.history())));
+        }
+        state.hints.hint(line, pos, ctx).map(Hint::Suggestion)
+    }
+}
+
+impl Highlighter for Helper {
+    #[cfg(any(feature = "bidi", feature = "tagger"))]
+    fn highlight<'l>(&self, line: &'l str, pos: usize) -> Cow<'l, str> {
+        let styles = self.styles(line, pos);
+        #[cfg(feature = "bidi")]
+        if let Some(reordered) = crate::bidi::reorder(line, Some(pos), &styles) {
+            self.state.lock().cursor_hidden = reordered.starts_with(crate::bidi::HIDE_CURSOR);
+            return Cow::Owned(reordered);
+        }
+        #[cfg(feature = "tagger")]
+        let painted = (!styles.is_empty()).then(|| crate::tagging::paint(line, &styles));
+        #[cfg(not(feature = "tagger"))]
+        let painted: Option<String> = None;
+        // NOTE: the cursor may have been hidden while the line held right-to-left text
+        #[cfg(feature = "bidi")]
+        if std::mem::take(&mut self.state.lock().cursor_hidden) {
+            return Cow::Owned(format!(
+                "{}{}",
+                crate::bidi::SHOW_CURSOR,
+                painted.as_deref().unwrap_or(line)
+            ));
+        }
+        painted.map_or(Cow::Borrowed(line), Cow::Owned)
+    }
+
+    #[cfg(any(feature = "bidi", feature = "tagger"))]
+    fn highlight_prompt<'b, 's: 'b, 'p: 'b>(
+        &'s self,
+        prompt: &'p str,
+        _default: bool,
+    ) -> Cow<'b, str> {
+        #[cfg(feature = "tagger")]
+        if let Some(highlighted) = self
+            .state
+            .lock()
+            .tagging
+            .as_ref()
+            .and_then(|tagging| tagging.highlight_prompt(prompt))
+        {
+            return Cow::Owned(highlighted);
+        }
+        #[cfg(feature = "bidi")]
+        if let Some(reordered) = crate::bidi::reorder(prompt, None, &[]) {
+            return Cow::Owned(reordered);
+        }
+        Cow::Borrowed(prompt)
+    }
+
+    #[cfg(any(feature = "bidi", feature = "tagger"))]
+    #[cfg_attr(not(feature = "bidi"), allow(unused_variables))]
+    fn highlight_char(&self, line: &str, _pos: usize, _forced: bool) -> bool {
+        // NOTE: any edit may change the language or the tokens around the cursor
+        #[cfg(feature = "tagger")]
+        if self.state.lock().tagging.is_some() {
+            return true;
+        }
+        #[cfg(feature = "spellcheck")]
+        if self.state.lock().spelling.is_some() {
+            return true;
+        }
+        // NOTE: the cursor is drawn by the highlighter on right-to-left text
+        #[cfg(feature = "bidi")]
+        if crate::bidi::has_rtl(line) {
+            return true;
+        }
+        false
+    }
+
+    fn highlight_hint<'h>(&self, hint: &'h str) -> Cow<'h, str> {
+        let state = self.state.lock();
+        if state.picker.is_active() {
+            return Cow::Owned(state.picker.highlight(hint));
+        }
+        Cow::Owned(format!("\x1b[90m{hint}\x1b[0m"))
+    }
+}
+
+impl Validator for Helper {}
+
+impl rustyline::Helper for Helper {}
+
+/// Key bindings for [`Textarea`](crate::Textarea), bound to [`Event::Any`].
+pub(crate) struct Keymap {
+    state: Shared,
+}
+
+impl Keymap {
+    #[inline]
+    pub(crate) const fn new(state: Shared) -> Self {
+        Self { state }
+    }
+}
+
+impl ConditionalEventHandler for Keymap {
+    fn handle(
+        &self,
+        evt: &Event,
+        n: RepeatCount,
+        _positive: bool,
+        ctx: &EventContext<'_>,
+    ) -> Option<rustyline::Cmd> {
+        use rustyline::{Cmd, KeyCode as K, KeyEvent as E, Modifiers as M};
+
+        let key = *evt.get(0)?;
+        let mut state = self.state.lock();
+        if let Some(cmd) = state.idle.handle(key, ctx.line()) {
+            return Some(cmd);
+        }
+        if let Some(cmd) = state.picker.handle(key, ctx.line()) {
+            return Some(cmd);
+        }
+        if let Some(cmd) = crate::words::handle(key, n, ctx.line(), ctx.pos()) {
+            return Some(cmd);
+        }
+        #[cfg(feature = "bidi")]
+        if let Some(cmd) = crate::bidi::handle(key, ctx.line(), ctx.pos()) {
+            return Some(cmd);
+        }
+        match key {
+            E(K::Char('F'), M::CTRL) if ctx.has_hint() && ctx.pos() == ctx.line().len() => {
+                Some(Cmd::CompleteHint)
+            }
+            #[cfg(feature = "shortcodes")]
+            E(K::Char(':'), M::NONE)
+                if state.shortcodes
+                    && crate::shortcodes::closes_shortcode(ctx.line(), ctx.pos()) =>
+            {
+                Some(Cmd::Complete)
+            }
+            #[cfg(feature = "spellcheck")]
+            E(K::Char('$'), M::ALT) if state.spelling.is_some() => {
+                if let Some(spelling) = &mut state.spelling {
+                    spelling.request();
+                }
+                Some(Cmd::Complete)
+            }
+            _ => None,
+        }
+    }
+}
+
+#[cfg(all(test, feature = "bidi"))]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn show_cursor_once_after_rtl() {
+        let helper = Helper::new(Shared::default());
+        assert!(matches!(helper.highlight("ab", 0), Cow::Borrowed("ab")));
+        assert!(helper
+            .highlight("ab سلام", 3)
+            .starts_with(crate::bidi::HIDE_CURSOR));
+        assert_eq!(helper.highlight("ab", 0), "\x1b[?25hab");
+        assert!(matches!(helper.highlight("ab", 0), Cow::Borrowed("ab")));
+    }
+}
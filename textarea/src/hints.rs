@@ -0,0 +1,62 @@
+//! Fish-style inline hints.
+
+use rustyline::{
+    hint::{Hinter, HistoryHinter},
+    Context,
+};
+
+/// User-supplied source of hints.
+///
+/// It takes the whole line and returns the text to be displayed after it.
+pub(crate) type Provider = Box<dyn Fn(&str) -> Option<String> + Send>;
+
+/// Hints shown after the cursor when it is at the end of the line.
+#[derive(Default)]
+pub(crate) struct Hints {
+    provider: Option<Provider>,
+    history: HistoryHinter,
+}
+
+impl Hints {
+    #[inline]
+    pub(crate) fn set_provider(&mut self, provider: Provider) {
+        self.provider = Some(provider);
+    }
+
+    /// Suggest a completion for `line`, preferring the user-supplied provider
+    /// and falling back to the most recent history entry starting with `line`.
+    pub(crate) fn hint(&self, line: &str, pos: usize, ctx: &Context<'_>) -> Option<String> {
+        if line.is_empty() || pos < line.len() {
+            return None;
+        }
+        self.provider
+            .as_ref()
+            .and_then(|provider| provider(line))
+            .filter(|hint| !hint.is_empty())
+            .or_else(|| self.history.hint(line, pos, ctx))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rustyline::history::{History, MemHistory};
+
+    use super::*;
+
+    #[test]
+    fn provider_then_history() {
+        let mut history = MemHistory::new();
+        history.add("hello world").unwrap();
+        let ctx = Context::new(&history);
+
+        let mut hints = Hints::default();
+        assert_eq!(hints.hint("hel", 3, &ctx), Some("lo world".to_owned()));
+        assert_eq!(hints.hint("hel", 2, &ctx), None);
+
+        hints.set_provider(Box::new(|line| {
+            line.ends_with("he").then(|| "licopter".to_owned())
+        }));
+        assert_eq!(hints.hint("the he", 6, &ctx), Some("licopter".to_owned()));
+        assert_eq!(hints.hint("hel", 3, &ctx), Some("lo world".to_owned()));
+    }
+}
@@ -0,0 +1,253 @@
+//! Fuzzy incremental history search.
+
+use std::fmt::Write;
+
+use rustyline::{
+    history::{History, SearchDirection},
+    Cmd, KeyCode, KeyEvent, Modifiers, Movement,
+};
+
+use crate::fuzzy;
+
+/// Maximum number of matches displayed at once.
+const HEIGHT: usize = 8;
+/// Maximum number of chars displayed for each match.
+const WIDTH: usize = 72;
+
+/// State of the history picker.
+///
+/// While active, the query lives here instead of in the line buffer,
+/// which is emptied and restored when the picker is closed.
+#[derive(Debug, Default)]
+pub(crate) struct Picker {
+    active: bool,
+    original: String,
+    query: String,
+    entries: Vec<String>,
+    matches: Vec<fuzzy::Match>,
+    selected: usize,
+    /// Char indices to emphasize, per displayed row.
+    emphasis: Vec<Vec<usize>>,
+}
+
+impl Picker {
+    #[inline]
+    pub(crate) const fn is_active(&self) -> bool {
+        self.active
+    }
+
+    /// Close the picker without touching the line buffer.
+    #[inline]
+    pub(crate) fn reset(&mut self) {
+        *self = Self::default();
+    }
+
+    /// Handle a key event, returning [`None`] if it should get its default behaviour.
+    pub(crate) fn handle(&mut self, key: KeyEvent, line: &str) -> Option<Cmd> {
+        use KeyCode as K;
+        use Modifiers as M;
+
+        if !self.active {
+            return match key {
+                KeyEvent(K::Char('r' | 'R'), M::ALT) => {
+                    self.active = true;
+                    self.original = line.to_owned();
+                    self.query = line.to_owned();
+                    self.selected = 0;
+                    Some(if line.is_empty() {
+                        Cmd::Repaint
+                    } else {
+                        Cmd::Kill(Movement::WholeBuffer)
+                    })
+                }
+                _ => None,
+            };
+        }
+
+        match key {
+            KeyEvent(K::Enter, M::NONE) | KeyEvent(K::Char('J'), M::CTRL) => {
+                let text = self
+                    .matches
+                    .get(self.selected)
+                    .map(|m| self.entries[m.index].clone())
+                    .unwrap_or_else(|| self.original.clone());
+                self.reset();
+                Some(Cmd::Insert(1, text))
+            }
+            KeyEvent(K::Esc, _)
+            | KeyEvent(K::Char('G'), M::CTRL)
+            | KeyEvent(K::Char('r' | 'R'), M::ALT) => {
+                let text = std::mem::take(&mut self.original);
+                self.reset();
+                Some(Cmd::Insert(1, text))
+            }
+            KeyEvent(K::Char('C'), M::CTRL) => {
+                self.reset();
+                None
+            }
+            KeyEvent(K::Up, M::NONE) | KeyEvent(K::Char('P'), M::CTRL) => {
+                self.selected = self.selected.saturating_sub(1);
+                Some(Cmd::Repaint)
+            }
+            KeyEvent(K::Down, M::NONE) | KeyEvent(K::Char('N'), M::CTRL) => {
+                if self.selected + 1 < self.matches.len() {
+                    self.selected += 1;
+                }
+                Some(Cmd::Repaint)
+            }
+            KeyEvent(K::PageUp, M::NONE) => {
+                self.selected = self.selected.saturating_sub(HEIGHT);
+                Some(Cmd::Repaint)
+            }
+            KeyEvent(K::PageDown, M::NONE) => {
+                self.selected = (self.selected + HEIGHT).min(self.matches.len().saturating_sub(1));
+                Some(Cmd::Repaint)
+            }
+            KeyEvent(K::Backspace, M::NONE) => {
+                self.query.pop();
+                self.selected = 0;
+                Some(Cmd::Repaint)
+            }
+            KeyEvent(K::Char(c), M::NONE) => {
+                self.query.push(c);
+                self.selected = 0;
+                Some(Cmd::Repaint)
+            }
+            _ => Some(Cmd::Noop),
+        }
+    }
+
+    /// Rank the entries of `history` against the current query
+    /// and render the result.
+    pub(crate) fn render(&mut self, history: &dyn History) -> String {
+        self.entries = (0..history.len())
+            .filter_map(|index| {
+                history
+                    .get(index, SearchDirection::Forward)
+                    .ok()
+                    .flatten()
+                    .map(|result| result.entry.into_owned())
+            })
+            .collect();
+        self.matches = fuzzy::rank(&self.query, &self.entries);
+        self.selected = self.selected.min(self.matches.len().saturating_sub(1));
+
+        let offset = (self.selected + 1).saturating_sub(HEIGHT);
+        let mut text = format!(
+            "  (fuzzy-search {}/{}) {}",
+            self.matches.len(),
+            self.entries.len(),
+            self.query
+        );
+        self.emphasis.clear();
+        for (row, m) in self.matches.iter().enumerate().skip(offset).take(HEIGHT) {
+            let marker = if row == self.selected { '>' } else { ' ' };
+            let (preview, emphasis) = preview(&self.entries[m.index], &m.positions);
+            let _ = write!(text, "\n{marker} {preview}");
+            self.emphasis.push(emphasis);
+        }
+        text
+    }
+
+    /// Style a rendered picker with ANSI escapes.
+    pub(crate) fn highlight(&self, text: &str) -> String {
+        let mut lines = text.split('\n');
+        let mut highlighted = format!("\x1b[2m{}\x1b[0m", lines.next().unwrap_or_default());
+        for (line, emphasis) in lines.zip(&self.emphasis) {
+            let style = if line.starts_with('>') { "1" } else { "2" };
+            let _ = write!(highlighted, "\n\x1b[{style}m");
+            for (i, c) in line.chars().enumerate() {
+                // NOTE: the first two chars are the marker and a space
+                if i >= 2 && emphasis.binary_search(&(i - 2)).is_ok() {
+                    let _ = write!(highlighted, "\x1b[4m{c}\x1b[24m");
+                } else {
+                    highlighted.push(c);
+                }
+            }
+            highlighted.push_str("\x1b[0m");
+        }
+        highlighted
+    }
+}
+
+/// Render an entry in a single line, mapping matched `positions` to the result.
+fn preview(entry: &str, positions: &[usize]) -> (String, Vec<usize>) {
+    let mut preview = String::new();
+    let mut width = WIDTH;
+    for (i, c) in entry.chars().enumerate() {
+        if i == WIDTH {
+            // NOTE: the last char displayed is replaced by the ellipsis
+            preview.pop();
+            preview.push('…');
+            width -= 1;
+            break;
+        }
+        preview.push(match c {
+            '\n' => '⏎',
+            '\t' => ' ',
+            c if c.is_control() => '�',
+            c => c,
+        });
+    }
+    let emphasis = positions.iter().copied().filter(|&i| i < width).collect();
+    (preview, emphasis)
+}
+
+#[cfg(test)]
+mod tests {
+    use rustyline::history::MemHistory;
+
+    use super::*;
+
+    #[test]
+    fn pick_entry() {
+        let mut history = MemHistory::new();
+        history.add("cargo build").unwrap();
+        history.add("git status").unwrap();
+        history.add("cargo test\n--workspace").unwrap();
+
+        let mut picker = Picker::default();
+        assert_eq!(picker.handle(KeyEvent::alt('r'), ""), Some(Cmd::Repaint));
+        assert!(picker.is_active());
+        picker.handle(KeyEvent::from('c'), "");
+        picker.handle(KeyEvent::from('t'), "");
+
+        let text = picker.render(&history);
+        assert!(text.contains("> cargo test⏎--workspace"));
+
+        assert_eq!(
+            picker.handle(KeyEvent(KeyCode::Enter, Modifiers::NONE), ""),
+            Some(Cmd::Insert(1, "cargo test\n--workspace".to_owned()))
+        );
+        assert!(!picker.is_active());
+    }
+
+    #[test]
+    fn cancel_restores_line() {
+        let mut picker = Picker::default();
+        assert_eq!(
+            picker.handle(KeyEvent::alt('r'), "draft"),
+            Some(Cmd::Kill(Movement::WholeBuffer))
+        );
+        assert_eq!(
+            picker.handle(KeyEvent::ctrl('G'), ""),
+            Some(Cmd::Insert(1, "draft".to_owned()))
+        );
+    }
+
+    #[test]
+    fn truncate_preview() {
+        let last = [WIDTH - 2, WIDTH - 1];
+
+        let entry = "x".repeat(WIDTH);
+        let (text, emphasis) = preview(&entry, &last);
+        assert_eq!(text, entry);
+        assert_eq!(emphasis, last);
+
+        let entry = "x".repeat(WIDTH + 1);
+        let (text, emphasis) = preview(&entry, &[WIDTH - 2, WIDTH - 1, WIDTH]);
+        assert_eq!(text.chars().count(), WIDTH);
+        assert!(text.ends_with("x…"));
+        assert_eq!(emphasis, [WIDTH - 2]);
+    }
+}
@@ -0,0 +1,890 @@
+//! A reader for [Hunspell](https://github.com/hunspell/hunspell) dictionaries.
+//!
+//! Only the parts of the format needed for checking single words and suggesting
+//! corrections are supported: prefixes and suffixes, including their cross products
+//! and two-level affixes given by continuation flags, `FLAG`, `SET`, `AF`, `TRY`, `REP`, `ICONV`, `IGNORE`, `NEEDAFFIX` and `FORBIDDENWORD`,
+//! and compounding with `COMPOUNDFLAG`, `COMPOUNDBEGIN`, `COMPOUNDMIDDLE`, `COMPOUNDEND`,
+//! `COMPOUNDMIN` and `ONLYINCOMPOUND`.
+//! Directives only used for suggestions or morphology, like `KEY` or `MAP`, are ignored.
+//! Any other directive, like `COMPOUNDRULE` or `CHECKSHARPS`, is skipped with a warning,
+//! so that words relying on it may be taken as misspelled.
+
+use std::collections::{HashMap, HashSet};
+
+use anyhow::{bail, Context, Result};
+
+/// Suggestions offered at most for a single word.
+const MAX_SUGGESTIONS: usize = 10;
+
+type Flag = u32;
+
+/// How flags are written in the dictionary.
+#[derive(Clone, Copy, Default)]
+enum FlagMode {
+    /// A single char per flag, the default.
+    #[default]
+    Char,
+    /// Two chars per flag.
+    Long,
+    /// Comma-separated numbers.
+    Num,
+}
+
+impl FlagMode {
+    fn parse(self, flags: &str) -> Result<Vec<Flag>> {
+        Ok(match self {
+            Self::Char => flags.chars().map(Flag::from).collect(),
+            Self::Long => {
+                let chars: Vec<_> = flags.chars().map(Flag::from).collect();
+                chars
+                    .chunks(2)
+                    .map(|pair| pair.iter().fold(0, |flag, &c| flag << 16 | c))
+                    .collect()
+            }
+            Self::Num => flags
+                .split(',')
+                .map(|flag| flag.trim().parse().context("invalid numeric flag"))
+                .collect::<Result<_>>()?,
+        })
+    }
+}
+
+/// A single char of an affix condition.
+#[derive(Debug, PartialEq, Eq)]
+enum Pattern {
+    Any,
+    Char(char),
+    Class { negated: bool, chars: Vec<char> },
+}
+
+impl Pattern {
+    fn matches(&self, c: char) -> bool {
+        match self {
+            Self::Any => true,
+            Self::Char(expected) => c == *expected,
+            Self::Class { negated, chars } => chars.contains(&c) != *negated,
+        }
+    }
+}
+
+fn parse_condition(condition: &str) -> Vec<Pattern> {
+    let mut patterns = Vec::new();
+    let mut chars = condition.chars();
+    while let Some(c) = chars.next() {
+        patterns.push(match c {
+            '.' => Pattern::Any,
+            '[' => {
+                let mut class: String = chars.by_ref().take_while(|&c| c != ']').collect();
+                let negated = class.starts_with('^');
+                if negated {
+                    class.remove(0);
+                }
+                Pattern::Class {
+                    negated,
+                    chars: class.chars().collect(),
+                }
+            }
+            c => Pattern::Char(c),
+        });
+    }
+    patterns
+}
+
+/// A prefix or suffix rule.
+struct Affix {
+    flag: Flag,
+    cross_product: bool,
+    /// Flags of the affixes allowed on top of this one.
+    continuation: Vec<Flag>,
+    strip: String,
+    add: String,
+    condition: Vec<Pattern>,
+}
+
+impl Affix {
+    /// Whether the condition holds at the start of `stem`, for prefixes.
+    fn matches_start(&self, stem: &str) -> bool {
+        let mut chars = stem.chars();
+        self.condition
+            .iter()
+            .all(|pattern| chars.next().is_some_and(|c| pattern.matches(c)))
+    }
+
+    /// Whether the condition holds at the end of `stem`, for suffixes.
+    fn matches_end(&self, stem: &str) -> bool {
+        let mut chars = stem.chars().rev();
+        self.condition
+            .iter()
+            .rev()
+            .all(|pattern| chars.next().is_some_and(|c| pattern.matches(c)))
+    }
+
+    /// The stem `word` would come from with this prefix.
+    fn unprefix(&self, word: &str) -> Option<String> {
+        let rest = word.strip_prefix(self.add.as_str())?;
+        let stem = format!("{}{rest}", self.strip);
+        (!stem.is_empty() && self.matches_start(&stem)).then_some(stem)
+    }
+
+    /// The stem `word` would come from with this suffix.
+    fn unsuffix(&self, word: &str) -> Option<String> {
+        let rest = word.strip_suffix(self.add.as_str())?;
+        let stem = format!("{rest}{}", self.strip);
+        (!stem.is_empty() && self.matches_end(&stem)).then_some(stem)
+    }
+}
+
+/// Directives that don't change which words are accepted.
+const IGNORED: &[&str] = &[
+    "AM",
+    "BREAK",
+    "FORBIDWARN",
+    "HOME",
+    "KEY",
+    "LANG",
+    "LEMMA_PRESENT",
+    "MAP",
+    "MAXCPDSUGS",
+    "MAXDIFF",
+    "MAXNGRAMSUGS",
+    "NAME",
+    "NOSPLITSUGS",
+    "NOSUGGEST",
+    "OCONV",
+    "ONLYMAXDIFF",
+    "PHONE",
+    "SUBSTANDARD",
+    "SUGSWITHDOTS",
+    "VERSION",
+    "WARN",
+    "WORDCHARS",
+];
+
+/// Flags allowing stems in compounds.
+#[derive(Default)]
+struct Compounding {
+    /// Allowed anywhere in a compound.
+    any: Option<Flag>,
+    begin: Option<Flag>,
+    middle: Option<Flag>,
+    end: Option<Flag>,
+    /// Only allowed in compounds.
+    only: Option<Flag>,
+    /// Minimum number of chars of each part.
+    min: usize,
+}
+
+/// Where a part sits in a compound.
+#[derive(Clone, Copy)]
+enum Part {
+    Begin,
+    Middle,
+    End,
+}
+
+impl Compounding {
+    #[inline]
+    const fn is_enabled(&self) -> bool {
+        self.any.is_some() || self.begin.is_some()
+    }
+
+    /// The flags allowing a stem at `part`, any of which is enough.
+    fn flags(&self, part: Part) -> impl Iterator<Item = Flag> {
+        let flag = match part {
+            Part::Begin => self.begin,
+            Part::Middle => self.middle,
+            Part::End => self.end,
+        };
+        self.any.into_iter().chain(flag)
+    }
+}
+
+/// A Hunspell dictionary.
+pub(crate) struct Dictionary {
+    words: HashMap<String, Vec<Vec<Flag>>>,
+    prefixes: Vec<Affix>,
+    suffixes: Vec<Affix>,
+    try_chars: Vec<char>,
+    replacements: Vec<(String, String)>,
+    /// Conversions applied to words before checking them.
+    conversions: Vec<(String, String)>,
+    /// Chars removed from words and affixes.
+    ignored: Vec<char>,
+    need_affix: Option<Flag>,
+    forbidden: Option<Flag>,
+    compounding: Compounding,
+}
+
+/// Decode the contents of a dictionary file written in `encoding`.
+fn decode(bytes: &[u8], encoding: &str) -> Result<String> {
+    match encoding.to_ascii_uppercase().as_str() {
+        "UTF-8" | "UTF8" => String::from_utf8(bytes.to_vec()).context("invalid UTF-8"),
+        "ISO8859-1" | "ISO-8859-1" | "LATIN1" => {
+            Ok(bytes.iter().copied().map(char::from).collect())
+        }
+        _ => bail!("unsupported encoding {encoding}"),
+    }
+}
+
+/// The encoding declared by `SET` in the affix file, if any.
+fn declared_encoding(aff: &[u8]) -> Option<String> {
+    aff.split(|&b| b == b'\n').find_map(|line| {
+        let line = std::str::from_utf8(line).ok()?.trim();
+        line.strip_prefix("SET")
+            .filter(|rest| rest.starts_with(char::is_whitespace))
+            .map(|rest| rest.trim().to_owned())
+    })
+}
+
+impl Dictionary {
+    /// Read a dictionary from the contents of its `.aff` and `.dic` files.
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error if either file is malformed or in an unsupported encoding.
+    pub(crate) fn from_bytes(aff: &[u8], dic: &[u8]) -> Result<Self> {
+        let encoding = declared_encoding(aff).unwrap_or_else(|| "UTF-8".to_owned());
+        let aff = decode(aff, &encoding).context("in the affix file")?;
+        let dic = decode(dic, &encoding).context("in the dictionary file")?;
+        Self::parse(&aff, &dic)
+    }
+
+    fn parse(aff: &str, dic: &str) -> Result<Self> {
+        let mut dictionary = Self {
+            words: HashMap::new(),
+            prefixes: Vec::new(),
+            suffixes: Vec::new(),
+            try_chars: Vec::new(),
+            replacements: Vec::new(),
+            conversions: Vec::new(),
+            ignored: Vec::new(),
+            need_affix: None,
+            forbidden: None,
+            compounding: Compounding {
+                min: 3,
+                ..Compounding::default()
+            },
+        };
+        let mut mode = FlagMode::default();
+        let mut aliases: Vec<Vec<Flag>> = Vec::new();
+        // NOTE: tables start with a header holding the number of entries that follow,
+        // and affix headers also tell whether their rules allow cross products
+        let mut tables: HashMap<&str, usize> = HashMap::new();
+        let mut affixes: HashMap<(&str, &str), (bool, usize)> = HashMap::new();
+        let mut unsupported = HashSet::new();
+
+        for (number, line) in aff.lines().enumerate() {
+            let fields: Vec<_> = line.split_whitespace().collect();
+            let context = || format!("on line {} of the affix file", number + 1);
+            let flag = |field: &str| -> Result<Option<Flag>> {
+                Ok(mode.parse(field).with_context(context)?.first().copied())
+            };
+            let Some(&directive) = fields.first() else {
+                continue;
+            };
+            if directive.starts_with('#') || IGNORED.contains(&directive) {
+                continue;
+            }
+
+            if let kind @ ("PFX" | "SFX") = directive {
+                let name = fields.get(1).copied().unwrap_or_default();
+                match affixes.get_mut(&(kind, name)) {
+                    Some((cross_product, remaining)) if *remaining > 0 => {
+                        *remaining -= 1;
+                        let [_, _, strip, add, rest @ ..] = fields.as_slice() else {
+                            bail!("malformed affix rule {}", context());
+                        };
+                        let (add, continuation) = add.split_once('/').unwrap_or((add, ""));
+                        let affix = Affix {
+                            flag: flag(name)?.with_context(context)?,
+                            cross_product: *cross_product,
+                            continuation: parse_flags(mode, &aliases, continuation)
+                                .with_context(context)?,
+                            strip: if *strip == "0" { "" } else { strip }.to_owned(),
+                            add: if add == "0" { "" } else { add }.to_owned(),
+                            condition: parse_condition(rest.first().copied().unwrap_or(".")),
+                        };
+                        if kind == "PFX" {
+                            dictionary.prefixes.push(affix);
+                        } else {
+                            dictionary.suffixes.push(affix);
+                        }
+                    }
+                    _ => {
+                        let [_, _, cross @ ("Y" | "N"), count] = fields.as_slice() else {
+                            bail!("malformed affix header {}", context());
+                        };
+                        let count = count.parse().with_context(context)?;
+                        affixes.insert((kind, name), (*cross == "Y", count));
+                    }
+                }
+                continue;
+            }
+
+            if let table @ ("AF" | "REP" | "ICONV") = directive {
+                let remaining = tables.entry(table).or_default();
+                if *remaining == 0 {
+                    let [_, count] = fields.as_slice() else {
+                        bail!("malformed {table} header {}", context());
+                    };
+                    *remaining = count.parse().with_context(context)?;
+                    continue;
+                }
+                *remaining -= 1;
+                match (table, fields.as_slice()) {
+                    ("AF", [_, flags, ..]) => {
+                        aliases.push(mode.parse(flags).with_context(context)?)
+                    }
+                    ("REP", [_, from, to, ..]) => dictionary
+                        .replacements
+                        .push(((*from).replace('_', " "), (*to).replace('_', " "))),
+                    ("ICONV", [_, from, to, ..]) => dictionary
+                        .conversions
+                        .push(((*from).to_owned(), (*to).to_owned())),
+                    _ => bail!("malformed {table} entry {}", context()),
+                }
+                continue;
+            }
+
+            match fields.as_slice() {
+                ["SET", ..] => {}
+                ["FLAG", "long", ..] => mode = FlagMode::Long,
+                ["FLAG", "num", ..] => mode = FlagMode::Num,
+                ["FLAG", "UTF-8", ..] => mode = FlagMode::Char,
+                ["TRY", chars, ..] => dictionary.try_chars = chars.chars().collect(),
+                ["IGNORE", chars, ..] => dictionary.ignored = chars.chars().collect(),
+                ["NEEDAFFIX" | "PSEUDOROOT", name, ..] => dictionary.need_affix = flag(name)?,
+                ["FORBIDDENWORD", name, ..] => dictionary.forbidden = flag(name)?,
+                ["COMPOUNDFLAG", name, ..] => dictionary.compounding.any = flag(name)?,
+                ["COMPOUNDBEGIN", name, ..] => dictionary.compounding.begin = flag(name)?,
+                ["COMPOUNDMIDDLE", name, ..] => dictionary.compounding.middle = flag(name)?,
+                ["COMPOUNDEND", name, ..] => dictionary.compounding.end = flag(name)?,
+                ["ONLYINCOMPOUND", name, ..] => dictionary.compounding.only = flag(name)?,
+                ["COMPOUNDMIN", min, ..] => {
+                    dictionary.compounding.min = min.parse::<usize>().with_context(context)?.max(1);
+                }
+                _ => {
+                    if unsupported.insert(directive) {
+                        log::warn!("skipping unsupported directive {directive} {}", context());
+                    }
+                }
+            }
+        }
+
+        // NOTE: the first line holds the approximate number of words
+        for line in dic.lines().skip(1) {
+            let entry = line.split(['\t', ' ']).next().unwrap_or_default();
+            if entry.is_empty() {
+                continue;
+            }
+            let (word, flags) = split_entry(entry);
+            let flags = parse_flags(mode, &aliases, flags)?;
+            let word = dictionary.strip_ignored(&word);
+            dictionary.words.entry(word).or_default().push(flags);
+        }
+        for affix in dictionary
+            .prefixes
+            .iter_mut()
+            .chain(dictionary.suffixes.iter_mut())
+        {
+            affix.add.retain(|c| !dictionary.ignored.contains(&c));
+        }
+        Ok(dictionary)
+    }
+
+    /// `word` without the chars of `IGNORE`.
+    fn strip_ignored(&self, word: &str) -> String {
+        word.chars().filter(|c| !self.ignored.contains(c)).collect()
+    }
+
+    /// `word` after the conversions of `ICONV`, longest first, and without the chars of `IGNORE`.
+    fn normalize(&self, word: &str) -> String {
+        let mut normalized = String::with_capacity(word.len());
+        let mut rest = word;
+        while let Some(c) = rest.chars().next() {
+            let conversion = self
+                .conversions
+                .iter()
+                .filter(|(from, _)| rest.starts_with(from.as_str()))
+                .max_by_key(|(from, _)| from.len());
+            if let Some((from, to)) = conversion {
+                normalized.push_str(to);
+                rest = &rest[from.len()..];
+            } else {
+                normalized.push(c);
+                rest = &rest[c.len_utf8()..];
+            }
+        }
+        self.strip_ignored(&normalized)
+    }
+
+    /// Whether the stem is in the dictionary with all of `flags`.
+    ///
+    /// Stems needing an affix are only valid if `affixed`,
+    /// and those only allowed in compounds only if `compound`.
+    fn has_stem(&self, stem: &str, flags: &[Flag], affixed: bool, compound: bool) -> bool {
+        self.words.get(stem).is_some_and(|homonyms| {
+            homonyms.iter().any(|entry| {
+                let has = |flag: Option<Flag>| flag.is_some_and(|flag| entry.contains(&flag));
+                !has(self.forbidden)
+                    && flags.iter().all(|flag| entry.contains(flag))
+                    && (affixed || !has(self.need_affix))
+                    && (compound || !has(self.compounding.only))
+            })
+        })
+    }
+
+    /// Whether `word` comes from a stem with all of `flags` and a suffix,
+    /// allowing `outer` on top of it if any, only with cross products if `cross`.
+    fn check_suffixed(
+        &self,
+        word: &str,
+        flags: &[Flag],
+        outer: Option<Flag>,
+        cross: bool,
+        compound: bool,
+    ) -> bool {
+        self.suffixes
+            .iter()
+            .filter(|suffix| !cross || suffix.cross_product)
+            .filter(|suffix| outer.is_none_or(|outer| suffix.continuation.contains(&outer)))
+            .any(|suffix| {
+                suffix.unsuffix(word).is_some_and(|stem| {
+                    self.has_stem(&stem, &[flags, &[suffix.flag]].concat(), true, compound)
+                })
+            })
+    }
+
+    /// Whether `word` is spelled correctly, exactly as written,
+    /// from a stem with all of `flags`, possibly in a compound.
+    ///
+    /// Stems take a suffix, a prefix or both if they allow cross products,
+    /// and each affix may take another one of its continuation flags.
+    fn check_affixed(&self, word: &str, flags: &[Flag], compound: bool) -> bool {
+        if self.has_stem(word, flags, false, compound) {
+            return true;
+        }
+        let suffixed = |word: &str, cross: bool, flags: &[Flag]| {
+            self.check_suffixed(word, flags, None, cross, compound)
+                || self
+                    .suffixes
+                    .iter()
+                    .filter(|suffix| !cross || suffix.cross_product)
+                    .any(|suffix| {
+                        suffix.unsuffix(word).is_some_and(|stem| {
+                            self.check_suffixed(&stem, flags, Some(suffix.flag), cross, compound)
+                        })
+                    })
+        };
+        if suffixed(word, false, flags) {
+            return true;
+        }
+        self.prefixes.iter().any(|prefix| {
+            prefix.unprefix(word).is_some_and(|stem| {
+                let with_prefix = [flags, &[prefix.flag]].concat();
+                self.has_stem(&stem, &with_prefix, true, compound)
+                    || (prefix.cross_product
+                        && (suffixed(&stem, true, &with_prefix)
+                            || self.check_suffixed(
+                                &stem,
+                                flags,
+                                Some(prefix.flag),
+                                true,
+                                compound,
+                            )))
+            })
+        })
+    }
+
+    /// Whether `word` is a compound of stems, the last of which may have affixes.
+    fn check_compound(&self, word: &str) -> bool {
+        let compounding = &self.compounding;
+        if !compounding.is_enabled() {
+            return false;
+        }
+        let bounds: Vec<_> = word
+            .char_indices()
+            .map(|(i, _)| i)
+            .chain([word.len()])
+            .collect();
+        let chars = bounds.len() - 1;
+        if chars < 2 * compounding.min {
+            return false;
+        }
+        let part = |start: usize, end: usize, part: Part| {
+            let text = &word[bounds[start]..bounds[end]];
+            compounding.flags(part).any(|flag| match part {
+                Part::End => self.check_affixed(text, &[flag], true),
+                Part::Begin | Part::Middle => self.has_stem(text, &[flag], false, true),
+            })
+        };
+
+        // NOTE: whether the chars up to each index split into a begin part and middle parts
+        let mut split = vec![false; chars + 1];
+        for end in compounding.min..=chars - compounding.min {
+            split[end] = part(0, end, Part::Begin)
+                || (compounding.min..=end - compounding.min)
+                    .any(|start| split[start] && part(start, end, Part::Middle));
+        }
+        (compounding.min..=chars - compounding.min)
+            .any(|start| split[start] && part(start, chars, Part::End))
+    }
+
+    /// Whether `word` is spelled correctly, exactly as written.
+    fn check_exact(&self, word: &str) -> bool {
+        let word = self.normalize(word);
+        self.check_affixed(&word, &[], false) || self.check_compound(&word)
+    }
+
+    /// Whether `word` is spelled correctly, allowing capitalization at the start of sentences.
+    #[must_use]
+    pub(crate) fn check(&self, word: &str) -> bool {
+        if self.check_exact(word) {
+            return true;
+        }
+        // NOTE: mixed case, like "wOrd", is never corrected
+        let lowercase = word.to_lowercase();
+        let capitalized = capitalize(&lowercase);
+        let uppercase = word.to_uppercase() == word;
+        if lowercase == word || !(uppercase || capitalized == word) {
+            return false;
+        }
+        self.check_exact(&lowercase) || (uppercase && self.check_exact(&capitalized))
+    }
+
+    /// Suggest corrections for `word`, most likely first.
+    #[must_use]
+    pub(crate) fn suggest(&self, word: &str) -> Vec<String> {
+        let capitalized = word.chars().next().is_some_and(char::is_uppercase);
+        let chars: Vec<_> = word.chars().collect();
+        let mut candidates = Vec::new();
+
+        for (from, to) in &self.replacements {
+            for (start, _) in word.match_indices(from.as_str()) {
+                candidates.push(format!(
+                    "{}{to}{}",
+                    &word[..start],
+                    &word[start + from.len()..]
+                ));
+            }
+        }
+        for i in 0..chars.len() {
+            // NOTE: swapped, missing, wrong and extra chars, in that order
+            if i + 1 < chars.len() {
+                let mut swapped = chars.clone();
+                swapped.swap(i, i + 1);
+                candidates.push(swapped.into_iter().collect());
+            }
+            for &c in &self.try_chars {
+                let mut inserted = chars.clone();
+                inserted.insert(i, c);
+                candidates.push(inserted.into_iter().collect());
+                let mut replaced = chars.clone();
+                replaced[i] = c;
+                candidates.push(replaced.into_iter().collect());
+            }
+            let mut deleted = chars.clone();
+            deleted.remove(i);
+            candidates.push(deleted.into_iter().collect());
+        }
+        for &c in &self.try_chars {
+            candidates.push(format!("{word}{c}"));
+        }
+        for i in 1..chars.len() {
+            let (first, second): (String, String) =
+                (chars[..i].iter().collect(), chars[i..].iter().collect());
+            candidates.push(format!("{first} {second}"));
+        }
+
+        let mut seen = HashSet::new();
+        candidates
+            .into_iter()
+            .filter(|candidate| candidate != word && !candidate.is_empty())
+            .map(|candidate| {
+                if capitalized {
+                    capitalize(&candidate)
+                } else {
+                    candidate
+                }
+            })
+            .filter(|candidate| candidate.split(' ').all(|word| self.check(word)))
+            .filter(|candidate| seen.insert(candidate.clone()))
+            .take(MAX_SUGGESTIONS)
+            .collect()
+    }
+}
+
+/// Parse `flags`, written as the index of an `AF` alias if there are any.
+fn parse_flags(mode: FlagMode, aliases: &[Vec<Flag>], flags: &str) -> Result<Vec<Flag>> {
+    if aliases.is_empty() || flags.is_empty() {
+        return mode.parse(flags);
+    }
+    flags
+        .parse::<usize>()
+        .ok()
+        .and_then(|index| aliases.get(index.checked_sub(1)?))
+        .cloned()
+        .with_context(|| format!("invalid flag alias {flags}"))
+}
+
+/// Split a dictionary entry into its word and flags, honouring escaped slashes.
+fn split_entry(entry: &str) -> (String, &str) {
+    let mut word = String::with_capacity(entry.len());
+    let mut chars = entry.char_indices();
+    while let Some((i, c)) = chars.next() {
+        match c {
+            '\\' if entry[i + 1..].starts_with('/') => {
+                word.push('/');
+                chars.next();
+            }
+            '/' => return (word, &entry[i + 1..]),
+            c => word.push(c),
+        }
+    }
+    (word, "")
+}
+
+fn capitalize(word: &str) -> String {
+    let mut chars = word.chars();
+    chars.next().map_or_else(String::new, |first| {
+        first.to_uppercase().chain(chars).collect()
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const AFF: &str = "SET UTF-8
+TRY esianrtolcdugmphbyfvkwzESIANRTOLCDUGMPHBYFVKWZ'
+REP 1
+REP f ph
+
+PFX U Y 1
+PFX U 0 un .
+
+SFX S Y 3
+SFX S y ies [^aeiou]y
+SFX S 0 s [aeiou]y
+SFX S 0 s [^y]
+
+SFX D Y 2
+SFX D 0 ed [^e]
+SFX D 0 d e
+";
+
+    const DIC: &str = "6
+happy/U
+try/SD
+play/SD
+photo/S
+word/S
+like/UD
+";
+
+    fn dictionary() -> Dictionary {
+        Dictionary::from_bytes(AFF.as_bytes(), DIC.as_bytes()).unwrap()
+    }
+
+    #[test]
+    fn check_words() {
+        let dictionary = dictionary();
+        for word in [
+            "happy", "unhappy", "tries", "plays", "played", "liked", "unliked", "words", "Words",
+            "WORDS",
+        ] {
+            assert!(dictionary.check(word), "{word}");
+        }
+        for word in ["happyy", "trys", "unword", "playes", "wordS", "ies"] {
+            assert!(!dictionary.check(word), "{word}");
+        }
+    }
+
+    #[test]
+    fn suggest_corrections() {
+        let dictionary = dictionary();
+        assert_eq!(dictionary.suggest("wrod")[0], "word");
+        assert_eq!(dictionary.suggest("fotos")[0], "photos");
+        assert_eq!(dictionary.suggest("Hapy"), ["Happy"]);
+        assert!(dictionary
+            .suggest("playword")
+            .contains(&"play word".to_owned()));
+    }
+
+    #[test]
+    fn affix_headers() {
+        // NOTE: a rule without condition may look like a header, but the header asked for two
+        let aff = "SFX A Y 2\nSFX A N 1\nSFX A 0 s .\n";
+        let dictionary = Dictionary::from_bytes(aff.as_bytes(), b"1\nfooN/A\n").unwrap();
+        assert!(dictionary.check("foo1"));
+        assert!(dictionary.check("fooNs"));
+
+        for aff in ["SFX A 0 s .\n", "SFX A Y 1\nSFX A 0 s .\nSFX A 0 es .\n"] {
+            let error = Dictionary::from_bytes(aff.as_bytes(), b"0\n")
+                .err()
+                .unwrap();
+            assert!(
+                error.to_string().starts_with("malformed affix header"),
+                "{error}"
+            );
+        }
+    }
+
+    #[test]
+    fn unsupported_directives() {
+        let aff =
+            "COMPOUNDRULE 1\nCOMPOUNDRULE AB\nCHECKSHARPS\nKEEPCASE K\nSFX S Y 1\nSFX S 0 s .\n";
+        let dictionary = Dictionary::from_bytes(aff.as_bytes(), b"1\nword/S\n").unwrap();
+        assert!(dictionary.check("words"));
+        assert!(Dictionary::from_bytes("KEY qwerty\nMAP 1\nMAP aá\n".as_bytes(), b"0\n").is_ok());
+    }
+
+    #[test]
+    fn real_world_headers() {
+        // NOTE: the headers of the en_US and de_DE dictionaries shipped with LibreOffice
+        let en_us = "SET UTF8
+TRY esianrtolcdugmphbyfvkwzESIANRTOLCDUGMPHBYFVKWZ'
+ICONV 1
+ICONV ’ '
+NOSUGGEST !
+
+# ordinal numbers
+COMPOUNDMIN 1
+# only in compounds: 1th, 2th, 3th
+ONLYINCOMPOUND c
+# compound rules:
+# 1. [0-9]*1[0-9]th (10th, 11th, 12th, 56714th, etc.)
+# 2. [0-9]*[02-9](1st|2nd|3rd|[4-9]th) (21st, 22nd, 123rd, 1234th, etc.)
+COMPOUNDRULE 2
+COMPOUNDRULE n*1t
+COMPOUNDRULE n*mp
+WORDCHARS 0123456789
+
+PFX A Y 1
+PFX A   0     re         .
+
+SFX N Y 3
+SFX N   e     ion        e
+SFX N   y     ication    y
+SFX N   0     en         [^ey]
+";
+        let dictionary =
+            Dictionary::from_bytes(en_us.as_bytes(), b"2\ncreate/AN\nquantify/N\n").unwrap();
+        for word in [
+            "create",
+            "recreate",
+            "creation",
+            "recreation",
+            "quantification",
+        ] {
+            assert!(dictionary.check(word), "{word}");
+        }
+
+        let de_de = "SET UTF-8
+LANG de_DE
+TRY esijanrtolcdugmphbyfvkwqxzäüößáéêàâñESIJANRTOLCDUGMPHBYFVKWQXZÄÜÖÉ-.
+WORDCHARS ßáéêàâñ.-
+KEY qwertzuiopü|asdfghjklöä|yxcvbnm|qaw|say|wse|dsx|sy|edr|fdc|dx|rft|gfv|fc|tgz|hgb|gv
+MAP 2
+MAP aä
+MAP oö
+BREAK 2
+BREAK -
+BREAK ^-
+CHECKSHARPS
+KEEPCASE w
+COMPOUNDBEGIN x
+COMPOUNDMIDDLE y
+COMPOUNDEND z
+FORBIDDENWORD d
+ONLYINCOMPOUND o
+COMPOUNDPERMITFLAG c
+CHECKCOMPOUNDCASE
+CHECKCOMPOUNDDUP
+
+SFX E Y 1
+SFX E   0     e          .
+";
+        let dictionary =
+            Dictionary::from_bytes(de_de.as_bytes(), "3\nHaus/xE\nboot/z\nWort/Ed\n".as_bytes())
+                .unwrap();
+        for word in ["Haus", "Hausboot"] {
+            assert!(dictionary.check(word), "{word}");
+        }
+        assert!(!dictionary.check("Wort"));
+    }
+
+    #[test]
+    fn two_level_affixes() {
+        let aff = "PFX U Y 1
+PFX U 0 un .
+SFX A Y 1
+SFX A 0 able/S .
+SFX S Y 1
+SFX S 0 s .
+SFX N Y 1
+SFX N 0 ness/U .
+";
+        let dic = "2\ndrink/A\nkind/N\n";
+        let dictionary = Dictionary::from_bytes(aff.as_bytes(), dic.as_bytes()).unwrap();
+        for word in ["drinkable", "drinkables", "kindness", "unkindness"] {
+            assert!(dictionary.check(word), "{word}");
+        }
+        for word in ["drinks", "undrinkable", "unkind", "kindnesss"] {
+            assert!(!dictionary.check(word), "{word}");
+        }
+    }
+
+    #[test]
+    fn aliases_and_conversions() {
+        let aff = "AF 2
+AF S
+AF SD
+ICONV 1
+ICONV ’ '
+IGNORE -
+SFX S Y 1
+SFX S 0 s .
+SFX D Y 1
+SFX D 0 ed .
+";
+        let dic = "3\nword/1\nplay/2\nit's\n";
+        let dictionary = Dictionary::from_bytes(aff.as_bytes(), dic.as_bytes()).unwrap();
+        for word in ["words", "played", "it’s", "it's", "pl-ay", "wo-rds"] {
+            assert!(dictionary.check(word), "{word}");
+        }
+        assert!(!dictionary.check("worded"));
+        assert!(Dictionary::from_bytes(aff.as_bytes(), b"1\nword/3\n").is_err());
+    }
+
+    #[test]
+    fn compounds() {
+        let aff = "COMPOUNDFLAG X
+COMPOUNDMIN 2
+ONLYINCOMPOUND O
+SFX S Y 1
+SFX S 0 s .
+";
+        let dic = "4\nfoot/X\nball/XS\nhand/X\nge/XO\n";
+        let dictionary = Dictionary::from_bytes(aff.as_bytes(), dic.as_bytes()).unwrap();
+        for word in [
+            "football",
+            "footballs",
+            "handball",
+            "footballhand",
+            "gehand",
+        ] {
+            assert!(dictionary.check(word), "{word}");
+        }
+        for word in ["ge", "foots", "ballsfoot", "footx"] {
+            assert!(!dictionary.check(word), "{word}");
+        }
+    }
+
+    #[test]
+    fn flag_modes() {
+        assert_eq!(FlagMode::Long.parse("AaBb").unwrap().len(), 2);
+        assert_eq!(FlagMode::Num.parse("1,23").unwrap(), [1, 23]);
+        assert_eq!(split_entry("and\\/or/S"), ("and/or".to_owned(), "S"));
+    }
+}
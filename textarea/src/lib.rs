@@ -1,16 +1,147 @@
 //! A textarea for the command-line, with multiline support.
+//!
+//! Press `alt+r` for a fuzzy search over the history:
+//! type to filter, use the arrows to select and `enter` to edit the selected entry.
+//!
+//! While typing, the most recent matching history entry is suggested in grey
+//! after the cursor, and accepted with `right` or `ctrl+f`.
+//!
+//! On Unix terminals, `interact_text_timeout` gives up once nobody types for a while,
+//! returning whatever was left in the buffer.
+//! Idle callbacks can print messages above the prompt while waiting.
+//!
+//! With the `shortcodes` feature, emoji shortcodes like `:rocket:` and Unicode names
+//! like `\alpha` can be typed and completed with `tab`.
+//!
+//! Besides the parsed value, `interact` returns the exact text submitted,
+//! whether it spans multiple lines and how long it took to write.
+//!
+//! With the `tagger` feature, the line is annotated as it is typed using
+//! `universal-tagger`: stop words are dimmed, numbers and punctuation are coloured
+//! and the detected language is shown in the prompt.
+//! Word motions (`alt+f`, `alt+b`, `alt+d` and `ctrl+w`) then follow the same token
+//! boundaries, so that they work across scripts, instead of the words of UAX #29.
+//!
+//! With the `bidi` feature, lines holding right-to-left text, like Arabic or Urdu,
+//! are shown in visual order following the Unicode Bidirectional Algorithm,
+//! and `left` and `right` move the cursor visually across them.
+//!
+//! With the `tagger` feature, `with_vocabulary` also completes words with `tab`
+//! from what was submitted before, most frequent first and leaving stop words out,
+//! and keeps the vocabulary in a file between sessions.
+//!
+//! With the `spellcheck` feature, `with_dictionary` loads Hunspell dictionaries:
+//! unknown words are underlined using the dictionary of the detected language,
+//! and `alt+$` offers corrections for the word at the cursor.
+//!
+//! `TextBuffer` offers the same editing commands without a terminal,
+//! from word motions to undo and the kill ring, for other front-ends and tests.
+
+#[cfg(feature = "bidi")]
+mod bidi;
+mod buffer;
+mod fuzzy;
+mod helper;
+mod hints;
+mod history_search;
+#[cfg(feature = "spellcheck")]
+mod hunspell;
+mod motion;
+#[cfg(feature = "shortcodes")]
+mod shortcodes;
+#[cfg(feature = "spellcheck")]
+mod spelling;
+#[cfg(feature = "tagger")]
+mod tagging;
+mod timeout;
+#[cfg(feature = "tagger")]
+mod vocabulary;
+mod words;
 
 use std::{
     fmt::{Debug, Display},
     marker::PhantomData,
     str::FromStr,
+    time::{Duration, Instant},
 };
 
-use anyhow::{Error, Result};
-use rustyline::{Cmd, Editor, KeyEvent};
+use anyhow::{bail, Error, Result};
+use rustyline::{history::DefaultHistory, Editor, Event, EventHandler};
+#[cfg(feature = "multiline")]
+use rustyline::{Cmd, KeyEvent};
+
+pub use crate::buffer::TextBuffer;
+use crate::helper::{Helper, Keymap, Shared};
+
+/// A value submitted by the user, along with the text they typed.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Submission<T> {
+    /// The exact text submitted.
+    pub raw: String,
+    /// The value parsed from the text.
+    pub value: T,
+    /// Whether the text spans multiple lines.
+    pub multiline: bool,
+    /// How long the user took, from the prompt being shown to the text being submitted.
+    pub elapsed: Duration,
+}
+
+/// The error returned when the submitted text can't be parsed,
+/// keeping the text so that it can still be audited.
+#[derive(Debug)]
+pub struct ParseError {
+    /// The exact text submitted.
+    pub raw: String,
+    source: Error,
+}
+
+impl Display for ParseError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "cannot parse {:?}: {}", self.raw, self.source)
+    }
+}
+
+impl std::error::Error for ParseError {
+    #[inline]
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        Some(self.source.as_ref())
+    }
+}
+
+impl<T> Submission<T> {
+    fn parse(raw: String, elapsed: Duration) -> Result<Self>
+    where
+        T: FromStr,
+        T::Err: Debug + Display + Send + Sync + 'static,
+    {
+        let value = match raw.parse() {
+            Ok(value) => value,
+            Err(error) => {
+                let source = Error::msg(error);
+                return Err(ParseError { raw, source }.into());
+            }
+        };
+        Ok(Self {
+            multiline: raw.contains('\n'),
+            raw,
+            value,
+            elapsed,
+        })
+    }
+}
+
+/// The outcome of [`Textarea::interact_timeout`] and [`Textarea::interact_text_timeout`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Outcome<T> {
+    /// The user submitted a value.
+    Submitted(T),
+    /// The user stopped typing for too long, leaving this partial text behind.
+    TimedOut(String),
+}
 
 pub struct Textarea<T> {
-    editor: Editor<()>,
+    editor: Editor<Helper, DefaultHistory>,
+    state: Shared,
     prompt: String,
     phantom: PhantomData<T>,
 }
@@ -24,12 +155,20 @@ impl<T> Textarea<T> {
     /// returns one.
     pub fn new() -> Result<Self> {
         let mut editor = Editor::new().map_err(Error::msg)?;
+        let state = Shared::default();
+
+        editor.set_helper(Some(Helper::new(state.clone())));
+        editor.bind_sequence(
+            Event::Any,
+            EventHandler::Conditional(Box::new(Keymap::new(state.clone()))),
+        );
 
         #[cfg(feature = "multiline")]
         editor.bind_sequence(KeyEvent::alt('\r'), Cmd::Newline);
 
         Ok(Self {
             editor,
+            state,
             prompt: String::new(),
             phantom: PhantomData,
         })
@@ -40,26 +179,290 @@ impl<T> Textarea<T> {
         self
     }
 
+    /// Suggest completions with `hinter` before falling back to the history.
+    ///
+    /// The hinter takes the whole line, whenever the cursor is at its end,
+    /// and returns the text to be suggested after it.
+    pub fn with_hinter<F>(&mut self, hinter: F) -> &mut Self
+    where
+        F: Fn(&str) -> Option<String> + Send + 'static,
+    {
+        self.state.lock().hints.set_provider(Box::new(hinter));
+        self
+    }
+
+    /// Add entries to the history, from oldest to newest.
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error if [`rustyline::Editor::add_history_entry`]
+    /// returns one.
+    pub fn with_history<I>(&mut self, entries: I) -> Result<&mut Self>
+    where
+        I: IntoIterator,
+        I::Item: AsRef<str> + Into<String>,
+    {
+        for entry in entries {
+            self.editor.add_history_entry(entry).map_err(Error::msg)?;
+        }
+        Ok(self)
+    }
+
+    /// Expand emoji shortcodes, like `:rocket:`, and Unicode names, like `\alpha`.
+    ///
+    /// Emoji shortcodes are expanded as soon as they are closed with a colon,
+    /// and both kinds of codes are completed with `tab`.
+    #[cfg(feature = "shortcodes")]
+    pub fn with_shortcodes(&mut self) -> &mut Self {
+        self.state.lock().shortcodes = true;
+        self
+    }
+
+    /// Annotate the line while it is typed, using `detector` to find out its language.
+    ///
+    /// Stop words are dimmed, numbers, punctuation and symbols are coloured,
+    /// and the detected language is shown at the start of the prompt.
+    #[cfg(feature = "tagger")]
+    pub fn with_tagger(&mut self, detector: universal_tagger::LanguageDetector) -> &mut Self {
+        self.state.lock().tagging = Some(tagging::Tagging::new(detector));
+        self
+    }
+
+    /// Complete words with `tab` from a vocabulary learnt from the submitted text,
+    /// persisted at `path` between sessions.
+    ///
+    /// Stop words of the detected language are left out,
+    /// and completions are ranked by how often they were submitted.
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error if the vocabulary at `path` exists but can't be read.
+    #[cfg(feature = "tagger")]
+    pub fn with_vocabulary<P: Into<std::path::PathBuf>>(&mut self, path: P) -> Result<&mut Self> {
+        let vocabulary = vocabulary::Vocabulary::load(path.into())?;
+        self.state.lock().vocabulary = Some(vocabulary);
+        Ok(self)
+    }
+
+    /// Check the spelling of text in `lang` with the Hunspell dictionary made of the files at `aff` and `dic`.
+    ///
+    /// Unknown words are underlined, using the dictionary of the detected language,
+    /// and corrections for the word at the cursor are suggested with `alt+$`.
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error if the dictionary can't be read or is malformed.
+    #[cfg(feature = "spellcheck")]
+    pub fn with_dictionary<P, Q>(
+        &mut self,
+        lang: universal_tagger::Language,
+        aff: P,
+        dic: Q,
+    ) -> Result<&mut Self>
+    where
+        P: AsRef<std::path::Path>,
+        Q: AsRef<std::path::Path>,
+    {
+        let dictionary =
+            hunspell::Dictionary::from_bytes(&std::fs::read(aff)?, &std::fs::read(dic)?)?;
+        self.state
+            .lock()
+            .spelling
+            .get_or_insert_with(spelling::Spelling::new)
+            .add(lang, dictionary);
+        Ok(self)
+    }
+
+    /// Call `callback` whenever the user stops typing for `interval`, and every `interval` after that.
+    ///
+    /// The callback takes the whole line and may return a message,
+    /// which is printed above the prompt before redrawing it.
+    pub fn with_idle_callback<F>(&mut self, interval: Duration, callback: F) -> &mut Self
+    where
+        F: FnMut(&str) -> Option<String> + Send + 'static,
+    {
+        self.state
+            .lock()
+            .idle
+            .set_callback(interval, Box::new(callback));
+        self
+    }
+
     /// Interact with the user.
     ///
+    /// The submitted text is added to the history.
+    ///
     /// # Errors
     ///
     /// This function returns an error if either [`rustyline::Editor::readline`] or [`FromStr::from_str`]
-    /// returns one.
+    /// returns one, the latter as a [`ParseError`] holding the submitted text.
+    /// It also returns an error if the vocabulary can't be saved.
     pub fn interact_text(&mut self) -> Result<T>
     where
         T: FromStr,
         T::Err: Debug + Display + Send + Sync + 'static,
     {
-        let line = self.editor.readline(&self.prompt).map_err(Error::msg)?;
-        let value = line.parse().map_err(Error::msg)?;
-        Ok(value)
+        self.interact().map(|submission| submission.value)
+    }
+
+    /// Interact with the user, keeping the submitted text along with the parsed value.
+    ///
+    /// The submitted text is added to the history.
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error if either [`rustyline::Editor::readline`] or [`FromStr::from_str`]
+    /// returns one, the latter as a [`ParseError`] holding the submitted text.
+    /// It also returns an error if the vocabulary can't be saved.
+    pub fn interact(&mut self) -> Result<Submission<T>>
+    where
+        T: FromStr,
+        T::Err: Debug + Display + Send + Sync + 'static,
+    {
+        let start = Instant::now();
+        let (Outcome::Submitted(raw) | Outcome::TimedOut(raw)) = self.readline(None)?;
+        Submission::parse(raw, start.elapsed())
+    }
+
+    /// Interact with the user, giving up once nobody types for `timeout`.
+    ///
+    /// The submitted text is added to the history, but partial text left behind on timeout is not.
+    /// Lines only time out on Unix terminals, which report the cursor position when asked;
+    /// if the terminal doesn't answer, the line times out at the next key instead.
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error if either [`rustyline::Editor::readline`] or [`FromStr::from_str`]
+    /// returns one, the latter as a [`ParseError`] holding the submitted text.
+    /// It also returns an error if the vocabulary can't be saved, or on other platforms than Unix.
+    pub fn interact_text_timeout(&mut self, timeout: Duration) -> Result<Outcome<T>>
+    where
+        T: FromStr,
+        T::Err: Debug + Display + Send + Sync + 'static,
+    {
+        Ok(match self.interact_timeout(timeout)? {
+            Outcome::Submitted(submission) => Outcome::Submitted(submission.value),
+            Outcome::TimedOut(raw) => Outcome::TimedOut(raw),
+        })
+    }
+
+    /// Interact with the user, keeping the submitted text along with the parsed value,
+    /// and giving up once nobody types for `timeout`.
+    ///
+    /// The submitted text is added to the history, but partial text left behind on timeout is not.
+    /// Lines only time out on Unix terminals, which report the cursor position when asked;
+    /// if the terminal doesn't answer, the line times out at the next key instead.
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error if either [`rustyline::Editor::readline`] or [`FromStr::from_str`]
+    /// returns one, the latter as a [`ParseError`] holding the submitted text.
+    /// It also returns an error if the vocabulary can't be saved, or on other platforms than Unix.
+    pub fn interact_timeout(&mut self, timeout: Duration) -> Result<Outcome<Submission<T>>>
+    where
+        T: FromStr,
+        T::Err: Debug + Display + Send + Sync + 'static,
+    {
+        let start = Instant::now();
+        Ok(match self.readline(Some(timeout))? {
+            Outcome::Submitted(raw) => Outcome::Submitted(Submission::parse(raw, start.elapsed())?),
+            Outcome::TimedOut(raw) => Outcome::TimedOut(raw),
+        })
+    }
+
+    /// Prepare for a new line, returning the prompt to be shown.
+    fn begin(&self) -> String {
+        let mut state = self.state.lock();
+        state.picker.reset();
+        state.idle.reset();
+        #[cfg(feature = "bidi")]
+        {
+            state.cursor_hidden = false;
+        }
+        #[cfg(feature = "spellcheck")]
+        if let Some(spelling) = &mut state.spelling {
+            spelling.reset();
+        }
+        #[cfg(feature = "tagger")]
+        if let Some(tagging) = &mut state.tagging {
+            tagging.reset();
+            return format!("{}{}", tagging::PLACEHOLDER, self.prompt);
+        }
+        self.prompt.clone()
+    }
+
+    /// Add a submitted line to the history, and to the vocabulary if any.
+    fn remember(&mut self, line: &str) -> Result<()> {
+        self.editor.add_history_entry(line).map_err(Error::msg)?;
+        #[cfg(feature = "tagger")]
+        if let Some(vocabulary) = &mut self.state.lock().vocabulary {
+            vocabulary.learn(line);
+            vocabulary.save()?;
+        }
+        Ok(())
+    }
+
+    /// Read a line, adding it to the history if submitted.
+    fn readline(&mut self, timeout: Option<Duration>) -> Result<Outcome<String>> {
+        use crate::timeout::Watchdog;
+
+        // NOTE: other terminals don't report the cursor position as keys
+        if timeout.is_some() && !cfg!(unix) {
+            bail!("lines can only time out on Unix terminals");
+        }
+        let prompt = self.begin();
+        let has_callback = self.state.lock().idle.has_callback();
+        let watchdog = if timeout.is_some() || has_callback {
+            // NOTE: without a terminal, lines are read as they come
+            self.editor
+                .create_external_printer()
+                .ok()
+                .map(|printer| Watchdog::spawn(self.state.clone(), timeout, printer))
+        } else {
+            None
+        };
+
+        let line = self.editor.readline(&prompt);
+        #[cfg(feature = "bidi")]
+        bidi::show_cursor();
+        drop(watchdog);
+        if let Some(line) = self.state.lock().idle.take_timed_out() {
+            return Ok(Outcome::TimedOut(line));
+        }
+
+        let line = line.map_err(Error::msg)?;
+        self.remember(&line)?;
+        Ok(Outcome::Submitted(line))
     }
 }
 
 #[cfg(test)]
 mod tests {
+    use super::*;
 
     #[test]
     fn it_works() {}
+
+    #[test]
+    fn submission_keeps_raw_text() {
+        let submission: Submission<u8> =
+            Submission::parse("42".to_owned(), Duration::ZERO).unwrap();
+        assert_eq!(submission.raw, "42");
+        assert_eq!(submission.value, 42);
+        assert!(!submission.multiline);
+
+        let submission: Submission<String> =
+            Submission::parse("first\nsecond".to_owned(), Duration::from_secs(3)).unwrap();
+        assert_eq!(submission.raw, "first\nsecond");
+        assert!(submission.multiline);
+        assert_eq!(submission.elapsed, Duration::from_secs(3));
+
+        let error = Submission::<u8>::parse("256".to_owned(), Duration::ZERO).unwrap_err();
+        let error = error.downcast_ref::<ParseError>().unwrap();
+        assert_eq!(error.raw, "256");
+        assert_eq!(
+            error.to_string(),
+            "cannot parse \"256\": number too large to fit in target type"
+        );
+    }
 }
@@ -0,0 +1,30 @@
+//! Motions to arbitrary positions of the line.
+//!
+//! [`rustyline`] can only move by chars, words of its own or up to a given char,
+//! so motions are expressed as searches for the char at the target position,
+//! which also keeps killed text in the kill ring.
+
+use rustyline::{CharSearch, Cmd, Movement};
+use unicode_segmentation::UnicodeSegmentation;
+
+/// Move the cursor from `pos` to `target`, or kill the text in between.
+pub(crate) fn motion(line: &str, pos: usize, target: usize, kill: bool) -> Cmd {
+    let cmd = if kill { Cmd::Kill } else { Cmd::Move };
+    let Some(c) = line[target..].chars().next() else {
+        return cmd(Movement::EndOfBuffer);
+    };
+    if target < pos {
+        let n = line[target..pos].matches(c).count();
+        return cmd(Movement::ViCharSearch(n, CharSearch::Backward(c)));
+    }
+
+    // NOTE: forward searches start after the grapheme at the cursor
+    let shift = pos + line[pos..].graphemes(true).next().map_or(0, str::len);
+    let n = line[shift..target].matches(c).count() + 1;
+    let search = if kill {
+        CharSearch::ForwardBefore(c)
+    } else {
+        CharSearch::Forward(c)
+    };
+    cmd(Movement::ViCharSearch(n, search))
+}
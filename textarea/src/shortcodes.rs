@@ -0,0 +1,326 @@
+//! Entry of emoji shortcodes, like `:rocket:`, and Unicode names, like `\alpha`.
+
+use rustyline::completion::Pair;
+
+/// Unicode characters by name, mostly following LaTeX.
+const NAMES: &[(&str, char)] = &[
+    // Greek letters
+    ("alpha", 'α'),
+    ("beta", 'β'),
+    ("gamma", 'γ'),
+    ("delta", 'δ'),
+    ("epsilon", 'ε'),
+    ("varepsilon", 'ϵ'),
+    ("zeta", 'ζ'),
+    ("eta", 'η'),
+    ("theta", 'θ'),
+    ("vartheta", 'ϑ'),
+    ("iota", 'ι'),
+    ("kappa", 'κ'),
+    ("lambda", 'λ'),
+    ("mu", 'μ'),
+    ("nu", 'ν'),
+    ("xi", 'ξ'),
+    ("omicron", 'ο'),
+    ("pi", 'π'),
+    ("varpi", 'ϖ'),
+    ("rho", 'ρ'),
+    ("varrho", 'ϱ'),
+    ("sigma", 'σ'),
+    ("varsigma", 'ς'),
+    ("tau", 'τ'),
+    ("upsilon", 'υ'),
+    ("phi", 'φ'),
+    ("varphi", 'ϕ'),
+    ("chi", 'χ'),
+    ("psi", 'ψ'),
+    ("omega", 'ω'),
+    ("Alpha", 'Α'),
+    ("Beta", 'Β'),
+    ("Gamma", 'Γ'),
+    ("Delta", 'Δ'),
+    ("Epsilon", 'Ε'),
+    ("Zeta", 'Ζ'),
+    ("Eta", 'Η'),
+    ("Theta", 'Θ'),
+    ("Iota", 'Ι'),
+    ("Kappa", 'Κ'),
+    ("Lambda", 'Λ'),
+    ("Mu", 'Μ'),
+    ("Nu", 'Ν'),
+    ("Xi", 'Ξ'),
+    ("Omicron", 'Ο'),
+    ("Pi", 'Π'),
+    ("Rho", 'Ρ'),
+    ("Sigma", 'Σ'),
+    ("Tau", 'Τ'),
+    ("Upsilon", 'Υ'),
+    ("Phi", 'Φ'),
+    ("Chi", 'Χ'),
+    ("Psi", 'Ψ'),
+    ("Omega", 'Ω'),
+    // Arrows
+    ("to", '→'),
+    ("gets", '←'),
+    ("rightarrow", '→'),
+    ("leftarrow", '←'),
+    ("uparrow", '↑'),
+    ("downarrow", '↓'),
+    ("leftrightarrow", '↔'),
+    ("updownarrow", '↕'),
+    ("nearrow", '↗'),
+    ("searrow", '↘'),
+    ("swarrow", '↙'),
+    ("nwarrow", '↖'),
+    ("Rightarrow", '⇒'),
+    ("Leftarrow", '⇐'),
+    ("Uparrow", '⇑'),
+    ("Downarrow", '⇓'),
+    ("Leftrightarrow", '⇔'),
+    ("implies", '⇒'),
+    ("impliedby", '⇐'),
+    ("iff", '⇔'),
+    ("mapsto", '↦'),
+    ("hookrightarrow", '↪'),
+    ("hookleftarrow", '↩'),
+    ("longrightarrow", '⟶'),
+    ("longleftarrow", '⟵'),
+    ("rightleftharpoons", '⇌'),
+    // Operators and relations
+    ("pm", '±'),
+    ("mp", '∓'),
+    ("times", '×'),
+    ("div", '÷'),
+    ("cdot", '⋅'),
+    ("circ", '∘'),
+    ("bullet", '∙'),
+    ("star", '⋆'),
+    ("ast", '∗'),
+    ("oplus", '⊕'),
+    ("ominus", '⊖'),
+    ("otimes", '⊗'),
+    ("odot", '⊙'),
+    ("dagger", '†'),
+    ("ddagger", '‡'),
+    ("setminus", '∖'),
+    ("sqrt", '√'),
+    ("cbrt", '∛'),
+    ("le", '≤'),
+    ("leq", '≤'),
+    ("ge", '≥'),
+    ("geq", '≥'),
+    ("ne", '≠'),
+    ("neq", '≠'),
+    ("ll", '≪'),
+    ("gg", '≫'),
+    ("approx", '≈'),
+    ("equiv", '≡'),
+    ("sim", '∼'),
+    ("simeq", '≃'),
+    ("cong", '≅'),
+    ("propto", '∝'),
+    ("mid", '∣'),
+    ("nmid", '∤'),
+    ("parallel", '∥'),
+    ("perp", '⊥'),
+    ("vdash", '⊢'),
+    ("models", '⊨'),
+    // Logic and sets
+    ("forall", '∀'),
+    ("exists", '∃'),
+    ("nexists", '∄'),
+    ("neg", '¬'),
+    ("lnot", '¬'),
+    ("land", '∧'),
+    ("wedge", '∧'),
+    ("lor", '∨'),
+    ("vee", '∨'),
+    ("top", '⊤'),
+    ("bot", '⊥'),
+    ("therefore", '∴'),
+    ("because", '∵'),
+    ("in", '∈'),
+    ("notin", '∉'),
+    ("ni", '∋'),
+    ("subset", '⊂'),
+    ("supset", '⊃'),
+    ("subseteq", '⊆'),
+    ("supseteq", '⊇'),
+    ("cup", '∪'),
+    ("cap", '∩'),
+    ("emptyset", '∅'),
+    ("varnothing", '∅'),
+    // Calculus and miscellaneous symbols
+    ("sum", '∑'),
+    ("prod", '∏'),
+    ("coprod", '∐'),
+    ("int", '∫'),
+    ("iint", '∬'),
+    ("iiint", '∭'),
+    ("oint", '∮'),
+    ("partial", '∂'),
+    ("nabla", '∇'),
+    ("infty", '∞'),
+    ("aleph", 'ℵ'),
+    ("hbar", 'ℏ'),
+    ("ell", 'ℓ'),
+    ("Re", 'ℜ'),
+    ("Im", 'ℑ'),
+    ("wp", '℘'),
+    ("angle", '∠'),
+    ("degree", '°'),
+    ("prime", '′'),
+    ("ldots", '…'),
+    ("cdots", '⋯'),
+    ("vdots", '⋮'),
+    ("ddots", '⋱'),
+    ("langle", '⟨'),
+    ("rangle", '⟩'),
+    ("lceil", '⌈'),
+    ("rceil", '⌉'),
+    ("lfloor", '⌊'),
+    ("rfloor", '⌋'),
+    ("checkmark", '✓'),
+    ("triangle", '△'),
+    ("square", '□'),
+    ("diamond", '⋄'),
+];
+
+/// Look up the Unicode character called `name`, as in `\name`.
+#[must_use]
+pub fn name(name: &str) -> Option<char> {
+    NAMES
+        .iter()
+        .find_map(|&(candidate, c)| (candidate == name).then_some(c))
+}
+
+/// Look up the emoji for `shortcode`, as in `:shortcode:`.
+#[must_use]
+pub fn emoji(shortcode: &str) -> Option<&'static str> {
+    emojis::get_by_shortcode(shortcode).map(emojis::Emoji::as_str)
+}
+
+/// A code being typed.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum Code<'line> {
+    /// An emoji shortcode, after a colon.
+    Emoji(&'line str),
+    /// A Unicode name, after a backslash.
+    Name(&'line str),
+}
+
+/// Find the code right before `pos`, returning it along with the byte index it starts at,
+/// including its colon or backslash.
+fn code_before(line: &str, pos: usize) -> Option<(usize, Code<'_>)> {
+    let head = &line[..pos];
+    let start =
+        head.rfind(|c: char| !(c.is_ascii_alphanumeric() || matches!(c, '_' | '+' | '-')))?;
+    let code = &head[start + 1..];
+    if code.is_empty() {
+        return None;
+    }
+    match head[start..].chars().next()? {
+        ':' => {
+            // NOTE: avoid expanding things like `12:30`
+            let previous = head[..start].chars().next_back();
+            previous
+                .is_none_or(|c| !c.is_alphanumeric())
+                .then_some((start, Code::Emoji(code)))
+        }
+        '\\' if code.chars().all(|c| c.is_ascii_alphanumeric()) => Some((start, Code::Name(code))),
+        _ => None,
+    }
+}
+
+/// Whether typing a colon at `pos` closes a known emoji shortcode.
+#[must_use]
+pub(crate) fn closes_shortcode(line: &str, pos: usize) -> bool {
+    matches!(code_before(line, pos), Some((_, Code::Emoji(code))) if emoji(code).is_some())
+}
+
+/// Complete the code right before `pos`.
+///
+/// This returns the byte index where the replacement starts and the candidates,
+/// which are reduced to a single candidate on exact matches.
+#[must_use]
+pub(crate) fn complete(line: &str, pos: usize) -> Option<(usize, Vec<Pair>)> {
+    let (start, code) = code_before(line, pos)?;
+    let candidates: Vec<_> = match code {
+        Code::Emoji(code) => {
+            if let Some(emoji) = emoji(code) {
+                vec![pair(format!(":{code}:"), emoji)]
+            } else {
+                let mut candidates: Vec<_> = emojis::iter()
+                    .flat_map(|emoji| emoji.shortcodes().map(move |code| (code, emoji.as_str())))
+                    .filter(|(candidate, _)| candidate.starts_with(code))
+                    .collect();
+                candidates.sort_unstable();
+                candidates
+                    .into_iter()
+                    .map(|(code, emoji)| pair(format!(":{code}:"), emoji))
+                    .collect()
+            }
+        }
+        Code::Name(code) => {
+            if let Some(c) = name(code) {
+                vec![pair(format!("\\{code}"), c.encode_utf8(&mut [0; 4]))]
+            } else {
+                NAMES
+                    .iter()
+                    .filter(|(name, _)| name.starts_with(code))
+                    .map(|(name, c)| pair(format!("\\{name}"), c.encode_utf8(&mut [0; 4])))
+                    .collect()
+            }
+        }
+    };
+    (!candidates.is_empty()).then_some((start, candidates))
+}
+
+fn pair(code: String, replacement: &str) -> Pair {
+    Pair {
+        display: format!("{code} {replacement}"),
+        replacement: replacement.to_owned(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn replacements(line: &str) -> Option<(usize, Vec<String>)> {
+        let (start, candidates) = complete(line, line.len())?;
+        let replacements = candidates.into_iter().map(|c| c.replacement).collect();
+        Some((start, replacements))
+    }
+
+    #[test]
+    fn exact_codes() {
+        assert_eq!(
+            replacements("ship it :rocket"),
+            Some((8, vec!["🚀".to_owned()]))
+        );
+        assert_eq!(replacements("\\alpha"), Some((0, vec!["α".to_owned()])));
+        assert_eq!(replacements("x \\to"), Some((2, vec!["→".to_owned()])));
+    }
+
+    #[test]
+    fn partial_codes() {
+        let (start, candidates) = replacements("\\lambd").unwrap();
+        assert_eq!(start, 0);
+        assert_eq!(candidates, ["λ"]);
+
+        let (_, candidates) = replacements(":thumbs").unwrap();
+        assert!(candidates.contains(&"👍".to_owned()));
+        assert!(candidates.contains(&"👎".to_owned()));
+
+        assert_eq!(replacements(":notanemojiatall"), None);
+        assert_eq!(replacements("\\"), None);
+    }
+
+    #[test]
+    fn closing_colon() {
+        assert!(closes_shortcode(":tada", 5));
+        assert!(!closes_shortcode(":tad", 4));
+        assert!(!closes_shortcode("at 12:30", 8));
+    }
+}
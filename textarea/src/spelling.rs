@@ -0,0 +1,199 @@
+//! Spell checking with Hunspell dictionaries, selected by the detected language.
+
+use std::ops::Range;
+
+use rustyline::completion::Pair;
+use universal_tagger::{token_positions, Language, LanguageDetector};
+
+use crate::{hunspell::Dictionary, tagging::types_word, words::is_letters};
+
+/// Byte ranges of the words in `line`.
+fn words(line: &str) -> impl Iterator<Item = Range<usize>> + '_ {
+    token_positions(line).filter_map(|(position, token)| {
+        let start = position.into_inner();
+        is_letters(token).then(|| start..start + token.as_ref().len())
+    })
+}
+
+/// Spell checking state of the line being edited.
+pub(crate) struct Spelling {
+    detector: LanguageDetector,
+    dictionaries: Vec<(Language, Dictionary)>,
+    language: Option<Language>,
+    /// The line last seen by [`Spelling::update`].
+    line: String,
+    /// Whether suggestions were asked for, instead of regular completions.
+    requested: bool,
+    /// The end of the word being corrected, once suggestions are offered.
+    word_end: Option<usize>,
+}
+
+impl Spelling {
+    #[inline]
+    pub(crate) fn new() -> Self {
+        Self {
+            detector: LanguageDetector::default(),
+            dictionaries: Vec::new(),
+            language: None,
+            line: String::new(),
+            requested: false,
+            word_end: None,
+        }
+    }
+
+    /// Use `dictionary` for text in `lang`, replacing any previous one.
+    pub(crate) fn add(&mut self, lang: Language, dictionary: Dictionary) {
+        self.dictionaries.retain(|(other, _)| *other != lang);
+        self.dictionaries.push((lang, dictionary));
+    }
+
+    /// Forget the detected language.
+    #[inline]
+    pub(crate) fn reset(&mut self) {
+        self.language = None;
+        self.line.clear();
+        self.requested = false;
+        self.word_end = None;
+    }
+
+    /// Detect the language of `line`, keeping the last detected one if undecided.
+    ///
+    /// As in [`Tagging::update`](crate::tagging::Tagging::update), detection is skipped
+    /// while the line is unchanged, or while a word is being typed once a language is detected.
+    pub(crate) fn update(&mut self, line: &str) {
+        if line.trim().is_empty() {
+            self.language = None;
+            self.line.clear();
+            return;
+        }
+        if line == self.line || (self.language.is_some() && types_word(&self.line, line)) {
+            line.clone_into(&mut self.line);
+            return;
+        }
+        line.clone_into(&mut self.line);
+        if let Some(lang) = self.detector.detect(line) {
+            self.language = Some(lang);
+        }
+    }
+
+    /// The dictionary for the detected language.
+    ///
+    /// Before any language is detected, a single dictionary is used regardless.
+    fn dictionary(&self) -> Option<&Dictionary> {
+        match (self.language, self.dictionaries.as_slice()) {
+            (Some(lang), dictionaries) => dictionaries
+                .iter()
+                .find_map(|(other, dictionary)| (*other == lang).then_some(dictionary)),
+            (None, [(_, dictionary)]) => Some(dictionary),
+            (None, _) => None,
+        }
+    }
+
+    /// Byte ranges of the misspelled words of `line`.
+    ///
+    /// The word at `pos` is left alone, as it may still be being typed.
+    pub(crate) fn misspelled(&self, line: &str, pos: usize) -> Vec<Range<usize>> {
+        let Some(dictionary) = self.dictionary() else {
+            return Vec::new();
+        };
+        words(line)
+            .filter(|word| !word.contains(&pos) && word.end != pos)
+            .filter(|word| !dictionary.check(&line[word.clone()]))
+            .collect()
+    }
+
+    /// Ask for suggestions on the next completion.
+    #[inline]
+    pub(crate) fn request(&mut self) {
+        self.requested = true;
+    }
+
+    /// Suggest corrections for the misspelled word at `pos`, if asked for.
+    ///
+    /// This returns the byte index where the word starts and the candidates,
+    /// which are empty if the word is correct or nothing better was found.
+    pub(crate) fn complete(&mut self, line: &str, pos: usize) -> Option<(usize, Vec<Pair>)> {
+        if !std::mem::take(&mut self.requested) {
+            return None;
+        }
+        Some(self.suggest(line, pos).unwrap_or((pos, Vec::new())))
+    }
+
+    fn suggest(&mut self, line: &str, pos: usize) -> Option<(usize, Vec<Pair>)> {
+        let dictionary = self.dictionary()?;
+        let word = words(line).find(|word| word.start <= pos && pos <= word.end)?;
+        if dictionary.check(&line[word.clone()]) {
+            return None;
+        }
+        let candidates: Vec<_> = dictionary
+            .suggest(&line[word.clone()])
+            .into_iter()
+            .map(|suggestion| Pair {
+                display: suggestion.clone(),
+                replacement: suggestion,
+            })
+            .collect();
+        if candidates.is_empty() {
+            return None;
+        }
+        self.word_end = Some(word.end);
+        Some((word.start, candidates))
+    }
+
+    /// Take the end of the word being corrected, which is replaced as a whole.
+    #[inline]
+    pub(crate) fn take_word_end(&mut self) -> Option<usize> {
+        self.word_end.take()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const AFF: &str = "SET UTF-8\nTRY otwrd\n\nSFX S Y 1\nSFX S 0 s .\n";
+    const DIC: &str = "2\nword/S\ntwo\n";
+
+    #[test]
+    fn misspelled_words() {
+        let mut spelling = Spelling::new();
+        spelling.add(
+            Language::Eng,
+            Dictionary::from_bytes(AFF.as_bytes(), DIC.as_bytes()).unwrap(),
+        );
+
+        let line = "two wrods, two words";
+        let misspelled = spelling.misspelled(line, line.len());
+        assert_eq!(misspelled.first(), Some(&(4..9)));
+        assert_eq!(misspelled.len(), 1);
+        assert!(spelling.misspelled(line, 6).is_empty());
+
+        assert!(spelling.complete(line, 6).is_none());
+        spelling.request();
+        let (start, candidates) = spelling.complete(line, 6).unwrap();
+        assert_eq!(start, 4);
+        assert_eq!(candidates[0].replacement, "words");
+        assert_eq!(spelling.take_word_end(), Some(9));
+    }
+
+    #[test]
+    fn detect_between_words() {
+        let mut spelling = Spelling::new();
+        let line = "The answer is 42, of course";
+        spelling.update(line);
+        assert_eq!(spelling.language, Some(Language::Eng));
+
+        // NOTE: detection is skipped while the line is unchanged or a word is being typed...
+        spelling.language = Some(Language::Deu);
+        spelling.update(line);
+        spelling.update("The answer is 42, of courses");
+        assert_eq!(spelling.language, Some(Language::Deu));
+
+        // ...but not once it is done
+        spelling.update("The answer is 42, of courses!");
+        assert_eq!(spelling.language, Some(Language::Eng));
+
+        spelling.update("");
+        assert_eq!(spelling.language, None);
+    }
+}
@@ -0,0 +1,189 @@
+//! Language-aware highlighting, powered by [`universal_tagger`].
+
+use std::{fmt::Write, ops::Range};
+
+use universal_tagger::{token_positions, Language, LanguageDetector, Tag, Tagger, Token};
+
+/// Placeholder for the detected language, at the start of the prompt.
+pub(crate) const PLACEHOLDER: &str = "··· ";
+
+/// Annotations of the line being edited.
+pub(crate) struct Tagging {
+    detector: LanguageDetector,
+    tagger: Option<Tagger>,
+    /// The line last seen by [`Tagging::update`].
+    line: String,
+}
+
+impl Tagging {
+    #[inline]
+    pub(crate) const fn new(detector: LanguageDetector) -> Self {
+        Self {
+            detector,
+            tagger: None,
+            line: String::new(),
+        }
+    }
+
+    /// The language detected so far, if any.
+    #[inline]
+    pub(crate) fn language(&self) -> Option<Language> {
+        self.tagger.as_ref().map(|tagger| tagger.lang)
+    }
+
+    /// Forget the detected language.
+    #[inline]
+    pub(crate) fn reset(&mut self) {
+        self.tagger = None;
+        self.line.clear();
+    }
+
+    /// Detect the language of `line`.
+    ///
+    /// The last detected language is kept until `line` is emptied,
+    /// so that it doesn't flicker while a word is being typed.
+    /// Detection is skipped while the line is unchanged,
+    /// or while a word is being typed once a language is detected.
+    pub(crate) fn update(&mut self, line: &str) {
+        if line.trim().is_empty() {
+            self.reset();
+            return;
+        }
+        if line == self.line || (self.tagger.is_some() && types_word(&self.line, line)) {
+            line.clone_into(&mut self.line);
+            return;
+        }
+        line.clone_into(&mut self.line);
+        if let Some(lang) = self.detector.detect(line) {
+            if self.language() != Some(lang) {
+                self.tagger = Some(Tagger::new(lang));
+            }
+        }
+    }
+
+    /// Show the detected language in place of the [`PLACEHOLDER`] in `prompt`.
+    pub(crate) fn highlight_prompt(&self, prompt: &str) -> Option<String> {
+        let rest = prompt.strip_prefix(PLACEHOLDER)?;
+        let lang = self.language()?;
+        // NOTE: ISO 639-3 codes fit the placeholder
+        Some(format!("\x1b[2m{lang:?}\x1b[0m {rest}"))
+    }
+
+    /// The SGR parameters of the tokens of `line`, by byte range.
+    pub(crate) fn styles(&self, line: &str) -> Vec<(Range<usize>, String)> {
+        let tokens: Vec<_> = match &self.tagger {
+            Some(tagger) => tagger
+                .tag(line)
+                .map(|(position, token, tag)| (position.into_inner(), token, tag))
+                .collect(),
+            None => token_positions(line)
+                .map(|(position, token)| (position.into_inner(), token, None))
+                .collect(),
+        };
+        tokens
+            .into_iter()
+            .filter_map(|(start, token, tag)| {
+                let style = style(token, tag)?;
+                Some((start..start + token.as_ref().len(), style.to_owned()))
+            })
+            .collect()
+    }
+}
+
+/// Whether `line` is the `last` line seen with a single letter or digit added at its end.
+pub(crate) fn types_word(last: &str, line: &str) -> bool {
+    line.strip_prefix(last).is_some_and(|rest| {
+        let mut chars = rest.chars();
+        chars.next().is_some_and(char::is_alphanumeric) && chars.next().is_none()
+    })
+}
+
+/// Underline the byte `ranges` on top of `styles`, which are kept sorted.
+#[cfg(feature = "spellcheck")]
+pub(crate) fn underline(
+    mut styles: Vec<(Range<usize>, String)>,
+    ranges: Vec<Range<usize>>,
+) -> Vec<(Range<usize>, String)> {
+    for range in ranges {
+        match styles.binary_search_by_key(&range.start, |(other, _)| other.start) {
+            Ok(index) => styles[index].1.push_str(";4"),
+            Err(index) => styles.insert(index, (range, "4".to_owned())),
+        }
+    }
+    styles
+}
+
+/// Write `line` with ANSI escapes for the SGR parameters of its byte ranges,
+/// which are sorted and don't overlap.
+pub(crate) fn paint(line: &str, styles: &[(Range<usize>, String)]) -> String {
+    let mut painted = String::with_capacity(line.len());
+    let mut end = 0;
+    for (range, style) in styles {
+        painted.push_str(&line[end..range.start]);
+        let _ = write!(painted, "\x1b[{style}m{}\x1b[0m", &line[range.clone()]);
+        end = range.end;
+    }
+    painted.push_str(&line[end..]);
+    painted
+}
+
+/// The SGR parameters used for `token`, if any.
+const fn style(token: Token<'_>, tag: Option<Tag>) -> Option<&'static str> {
+    match (token, tag) {
+        (_, Some(Tag::StopWord)) => Some("2"),
+        (
+            Token::Number(_)
+            | Token::NumberOrPunctuation(_)
+            | Token::NumberOrMark(_)
+            | Token::NumberOrOther(_),
+            _,
+        ) => Some("36"),
+        (Token::Punctuation(_) | Token::OtherOrPunctuation(_), _) => Some("33"),
+        (Token::Symbol(_), _) => Some("35"),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn highlight_english() {
+        let mut tagging = Tagging::new(LanguageDetector::default());
+        let line = "The answer is 42, of course!";
+        tagging.update(line);
+        assert_eq!(tagging.language(), Some(Language::Eng));
+        assert_eq!(
+            paint(line, &tagging.styles(line)),
+            "\x1b[2mThe\x1b[0m answer \x1b[2mis\x1b[0m \x1b[36m42\x1b[0m\x1b[33m,\x1b[0m \
+             \x1b[2mof\x1b[0m \x1b[2mcourse\x1b[0m\x1b[33m!\x1b[0m"
+        );
+        assert_eq!(
+            tagging.highlight_prompt("··· > ").as_deref(),
+            Some("\x1b[2mEng\x1b[0m > ")
+        );
+
+        tagging.update("");
+        assert_eq!(tagging.language(), None);
+        assert_eq!(tagging.highlight_prompt("··· > "), None);
+    }
+
+    #[test]
+    fn detect_between_words() {
+        let mut tagging = Tagging::new(LanguageDetector::default());
+        let line = "The answer is 42, of course";
+        tagging.update(line);
+        assert_eq!(tagging.language(), Some(Language::Eng));
+
+        // NOTE: detection is skipped while the line is unchanged or a word is being typed...
+        tagging.tagger = Some(Tagger::new(Language::Deu));
+        tagging.update(line);
+        tagging.update("The answer is 42, of courses");
+        assert_eq!(tagging.language(), Some(Language::Deu));
+
+        // ...but not once it is done
+        tagging.update("The answer is 42, of courses!");
+        assert_eq!(tagging.language(), Some(Language::Eng));
+    }
+}
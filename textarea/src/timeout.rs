@@ -0,0 +1,364 @@
+//! Input timeouts and idle callbacks.
+//!
+//! [`rustyline`] blocks on the terminal until a key is pressed
+//! and offers no way to give up on the line from another thread.
+//! On timeout, a [`Watchdog`] thread prints [`WAKE`] with an [`ExternalPrinter`],
+//! asking the terminal to report a known cursor position twice.
+//! The reports reach [`rustyline`] as unknown escape sequences,
+//! and the [`Keymap`](crate::helper::Keymap) takes two in a row to abort the line.
+//! Nothing is done to the standard input, and no signal is sent.
+//!
+//! [`rustyline`] reads every unsupported escape sequence the same way,
+//! so a single unknown key can't pass for the reports.
+//! Terminals that don't answer within [`REPORT_WINDOW`] end the line at the next key instead.
+
+use std::{
+    sync::{Arc, Condvar, Mutex, PoisonError},
+    thread::{self, JoinHandle},
+    time::{Duration, Instant},
+};
+
+use rustyline::{Cmd, ExternalPrinter, KeyCode, KeyEvent, Modifiers};
+
+use crate::helper::Shared;
+
+/// Save the cursor, move it home, report its position twice and restore it.
+///
+/// The printer redraws the prompt on a fresh line unless the message ends with one,
+/// so this also moves up a line beforehand.
+const WAKE: &str = "\x1b7\x1b[1;1H\x1b[6n\x1b[6n\x1b8\x1b[A\n";
+/// How [`rustyline`] reads each report of [`WAKE`], `\x1b[1;1R`.
+const REPORT: KeyEvent = KeyEvent(KeyCode::UnknownEscSeq, Modifiers::NONE);
+/// How many reports in a row abort the line.
+const REPORTS: usize = 2;
+/// How long the terminal has to answer [`WAKE`].
+const REPORT_WINDOW: Duration = Duration::from_secs(1);
+
+/// Called with the line buffer whenever the user stays idle for an interval,
+/// returning a message to be printed above the prompt.
+pub(crate) type Callback = Box<dyn FnMut(&str) -> Option<String> + Send>;
+
+/// Idle state of the line being edited.
+pub(crate) struct Idle {
+    last_activity: Instant,
+    line: String,
+    callback: Option<(Duration, Callback)>,
+    /// When [`WAKE`] was printed, while its reports are awaited.
+    waking: Option<Instant>,
+    /// How many reports in a row were received while waking.
+    reports: usize,
+    /// The partial line buffer, once timed out.
+    timed_out: Option<String>,
+}
+
+impl Default for Idle {
+    #[inline]
+    fn default() -> Self {
+        Self {
+            last_activity: Instant::now(),
+            line: String::new(),
+            callback: None,
+            waking: None,
+            reports: 0,
+            timed_out: None,
+        }
+    }
+}
+
+impl Idle {
+    #[inline]
+    pub(crate) fn set_callback(&mut self, interval: Duration, callback: Callback) {
+        self.callback = Some((interval, callback));
+    }
+
+    #[inline]
+    pub(crate) const fn has_callback(&self) -> bool {
+        self.callback.is_some()
+    }
+
+    /// Start over for a new line.
+    #[inline]
+    pub(crate) fn reset(&mut self) {
+        self.touch();
+        self.line.clear();
+        self.waking = None;
+        self.reports = 0;
+        self.timed_out = None;
+    }
+
+    /// Record user activity.
+    #[inline]
+    pub(crate) fn touch(&mut self) {
+        self.last_activity = Instant::now();
+    }
+
+    /// Record the current line buffer.
+    #[inline]
+    pub(crate) fn set_line(&mut self, line: &str) {
+        line.clone_into(&mut self.line);
+    }
+
+    /// Take the partial line buffer if the line timed out.
+    #[inline]
+    pub(crate) fn take_timed_out(&mut self) -> Option<String> {
+        self.timed_out.take()
+    }
+
+    /// Handle a key event, returning [`None`] if it should get its default behaviour.
+    pub(crate) fn handle(&mut self, key: KeyEvent, line: &str) -> Option<Cmd> {
+        let Some(woken) = self.waking else {
+            self.touch();
+            return None;
+        };
+        if key == REPORT {
+            self.reports += 1;
+            if self.reports < REPORTS {
+                return Some(Cmd::Noop);
+            }
+        } else if woken.elapsed() < REPORT_WINDOW {
+            self.reports = 0;
+            return None;
+        }
+        // NOTE: past the window, the terminal isn't answering, so any key ends the line
+        self.waking = None;
+        self.reports = 0;
+        self.timed_out = Some(line.to_owned());
+        Some(Cmd::Interrupt)
+    }
+}
+
+/// A thread watching the user while a line is being edited.
+///
+/// The thread is stopped when dropped.
+pub(crate) struct Watchdog {
+    stop: Arc<(Mutex<bool>, Condvar)>,
+    handle: Option<JoinHandle<()>>,
+}
+
+impl Watchdog {
+    /// Watch the line being read by the current thread.
+    ///
+    /// The line is aborted after `timeout` without user activity, if any.
+    /// Messages returned by the idle callback, and [`WAKE`], are written with `printer`.
+    pub(crate) fn spawn<P>(state: Shared, timeout: Option<Duration>, printer: P) -> Self
+    where
+        P: ExternalPrinter + Send + 'static,
+    {
+        let stop = Arc::new((Mutex::new(false), Condvar::new()));
+        let handle = {
+            let stop = Arc::clone(&stop);
+            thread::spawn(move || watch(&state, &stop, timeout, printer))
+        };
+        Self {
+            stop,
+            handle: Some(handle),
+        }
+    }
+}
+
+impl Drop for Watchdog {
+    fn drop(&mut self) {
+        let (stopped, condvar) = &*self.stop;
+        *stopped.lock().unwrap_or_else(PoisonError::into_inner) = true;
+        condvar.notify_all();
+        if let Some(handle) = self.handle.take() {
+            let _ = handle.join();
+        }
+    }
+}
+
+fn watch<P: ExternalPrinter>(
+    state: &Shared,
+    (stopped, condvar): &(Mutex<bool>, Condvar),
+    timeout: Option<Duration>,
+    mut printer: P,
+) {
+    let mut seen = None;
+    let mut tick = None;
+    loop {
+        let now = Instant::now();
+        let mut deadline = None;
+        let mut due = None;
+        {
+            let mut guard = state.lock();
+            let idle = &mut guard.idle;
+            if let Some(timeout) = timeout {
+                let expiry = idle.last_activity + timeout;
+                if now >= expiry {
+                    idle.waking = Some(now);
+                    drop(guard);
+                    // NOTE: printing may block until the line is redrawn, so it runs unlocked,
+                    // and on failure, the user can still submit the line
+                    let _ = printer.print(WAKE.to_owned());
+                    return;
+                }
+                deadline = Some(expiry);
+            }
+
+            if let Some((interval, _)) = idle.callback {
+                if seen != Some(idle.last_activity) {
+                    seen = Some(idle.last_activity);
+                    tick = Some(idle.last_activity + interval);
+                }
+                if tick.is_some_and(|tick| now >= tick) {
+                    tick = Some(now + interval);
+                    due = idle
+                        .callback
+                        .take()
+                        .map(|callback| (callback, idle.line.clone()));
+                }
+                deadline = match (deadline, tick) {
+                    (Some(deadline), Some(tick)) => Some(deadline.min(tick)),
+                    (deadline, tick) => deadline.or(tick),
+                };
+            }
+        }
+
+        // NOTE: the callback runs unlocked, so that the line can still be edited
+        if let Some(((interval, mut callback), line)) = due {
+            let message = callback(&line);
+            state.lock().idle.callback = Some((interval, callback));
+            if let Some(message) = message {
+                let _ = printer.print(message);
+            }
+            continue;
+        }
+
+        let guard = stopped.lock().unwrap_or_else(PoisonError::into_inner);
+        let guard = match deadline {
+            Some(deadline) => {
+                condvar
+                    .wait_timeout_while(guard, deadline.saturating_duration_since(now), |stopped| {
+                        !*stopped
+                    })
+                    .unwrap_or_else(PoisonError::into_inner)
+                    .0
+            }
+            None => condvar
+                .wait_while(guard, |stopped| !*stopped)
+                .unwrap_or_else(PoisonError::into_inner),
+        };
+        if *guard {
+            return;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::mpsc;
+
+    use super::*;
+
+    /// A printer sending its messages over a channel.
+    struct Printer(mpsc::Sender<String>);
+
+    impl ExternalPrinter for Printer {
+        fn print(&mut self, msg: String) -> rustyline::Result<()> {
+            let _ = self.0.send(msg);
+            Ok(())
+        }
+    }
+
+    fn spawn(state: &Shared, timeout: Option<Duration>) -> (Watchdog, mpsc::Receiver<String>) {
+        let (sender, receiver) = mpsc::channel();
+        let watchdog = Watchdog::spawn(state.clone(), timeout, Printer(sender));
+        (watchdog, receiver)
+    }
+
+    #[test]
+    fn report_is_ignored_unless_waking() {
+        let mut idle = Idle::default();
+        idle.set_line("draft");
+        assert_eq!(idle.handle(REPORT, "draft"), None);
+        assert_eq!(idle.take_timed_out(), None);
+    }
+
+    #[test]
+    fn unknown_key_is_not_a_report() {
+        let mut idle = Idle {
+            waking: Some(Instant::now()),
+            ..Idle::default()
+        };
+        assert_eq!(idle.handle(REPORT, "draft"), Some(Cmd::Noop));
+        assert_eq!(idle.handle(KeyEvent::from('a'), "draft"), None);
+        assert_eq!(idle.handle(REPORT, "draft"), Some(Cmd::Noop));
+        assert_eq!(idle.take_timed_out(), None);
+        assert_eq!(idle.handle(REPORT, "draft"), Some(Cmd::Interrupt));
+        assert_eq!(idle.take_timed_out().as_deref(), Some("draft"));
+    }
+
+    #[test]
+    fn silent_terminal_times_out_at_next_key() {
+        let mut idle = Idle {
+            waking: Instant::now().checked_sub(REPORT_WINDOW),
+            ..Idle::default()
+        };
+        assert_eq!(
+            idle.handle(KeyEvent::from('a'), "draft"),
+            Some(Cmd::Interrupt)
+        );
+        assert_eq!(idle.take_timed_out().as_deref(), Some("draft"));
+        assert_eq!(idle.handle(KeyEvent::from('a'), ""), None);
+    }
+
+    #[test]
+    fn time_out() {
+        let state = Shared::default();
+        state.lock().idle.reset();
+        let (watchdog, messages) = spawn(&state, Some(Duration::from_millis(20)));
+        assert_eq!(
+            messages.recv_timeout(Duration::from_secs(5)).as_deref(),
+            Ok(WAKE)
+        );
+        drop(watchdog);
+
+        let idle = &mut state.lock().idle;
+        assert_eq!(
+            idle.handle(KeyEvent::from('a'), "draft"),
+            None,
+            "keys still reach the line while waking"
+        );
+        assert_eq!(idle.handle(REPORT, "draft"), Some(Cmd::Noop));
+        assert_eq!(idle.handle(REPORT, "draft"), Some(Cmd::Interrupt));
+        assert_eq!(idle.take_timed_out().as_deref(), Some("draft"));
+        assert_eq!(idle.handle(REPORT, ""), None);
+    }
+
+    #[test]
+    fn activity_delays_timeout() {
+        let state = Shared::default();
+        state.lock().idle.reset();
+        let timeout = Duration::from_millis(200);
+        let start = Instant::now();
+        let (watchdog, messages) = spawn(&state, Some(timeout));
+        thread::sleep(timeout / 2);
+        state.lock().idle.touch();
+        assert_eq!(
+            messages.recv_timeout(Duration::from_secs(5)).as_deref(),
+            Ok(WAKE)
+        );
+        assert!(start.elapsed() >= timeout * 3 / 2);
+        drop(watchdog);
+    }
+
+    #[test]
+    fn idle_callback() {
+        let state = Shared::default();
+        state.lock().idle.reset();
+        state.lock().idle.set_line("draft");
+        state.lock().idle.set_callback(
+            Duration::from_millis(10),
+            Box::new(|line| Some(format!("still writing {line:?}?"))),
+        );
+        let (watchdog, messages) = spawn(&state, None);
+        for _ in 0..2 {
+            assert_eq!(
+                messages.recv_timeout(Duration::from_secs(5)).as_deref(),
+                Ok("still writing \"draft\"?")
+            );
+        }
+        drop(watchdog);
+        assert!(state.lock().idle.waking.is_none());
+    }
+}
@@ -0,0 +1,171 @@
+//! Word completion from a vocabulary learnt from submitted text.
+//!
+//! The vocabulary is persisted as a plain text file, one word per line
+//! preceded by how many times it was submitted and a tab.
+
+use std::{collections::HashMap, fs, io, path::PathBuf};
+
+use rustyline::completion::Pair;
+use universal_tagger::{token_positions, LanguageDetector, Tag, Tagger};
+
+use crate::words::is_letters;
+
+/// Words shorter than this, in chars, are not worth completing.
+const MIN_LEN: usize = 3;
+
+/// Words submitted by the user, with how many times each was submitted.
+pub(crate) struct Vocabulary {
+    path: PathBuf,
+    counts: HashMap<String, u64>,
+    detector: LanguageDetector,
+    /// The tagger for the language last detected, kept for text too short to tell.
+    tagger: Option<Tagger>,
+}
+
+impl Vocabulary {
+    /// Load the vocabulary stored at `path`, if any.
+    pub(crate) fn load(path: PathBuf) -> io::Result<Self> {
+        let counts = match fs::read_to_string(&path) {
+            Ok(contents) => parse(&contents),
+            Err(error) if error.kind() == io::ErrorKind::NotFound => HashMap::new(),
+            Err(error) => return Err(error),
+        };
+        Ok(Self {
+            path,
+            counts,
+            detector: LanguageDetector::default(),
+            tagger: None,
+        })
+    }
+
+    /// Learn the words of `text`, except stop words of its language.
+    pub(crate) fn learn(&mut self, text: &str) {
+        if let Some(lang) = self.detector.detect(text) {
+            if self.tagger.as_ref().map(|tagger| tagger.lang) != Some(lang) {
+                self.tagger = Some(Tagger::new(lang));
+            }
+        }
+        let words: Vec<_> = match &self.tagger {
+            Some(tagger) => tagger
+                .tag(text)
+                .filter(|&(_, _, tag)| tag != Some(Tag::StopWord))
+                .map(|(_, token, _)| token)
+                .collect(),
+            None => token_positions(text).map(|(_, token)| token).collect(),
+        };
+        for token in words {
+            let word = token.as_ref();
+            if is_letters(token) && word.chars().count() >= MIN_LEN {
+                *self.counts.entry(word.to_owned()).or_default() += 1;
+            }
+        }
+    }
+
+    /// Write the vocabulary back to its file, most frequent words first.
+    pub(crate) fn save(&self) -> io::Result<()> {
+        if let Some(parent) = self
+            .path
+            .parent()
+            .filter(|parent| !parent.as_os_str().is_empty())
+        {
+            fs::create_dir_all(parent)?;
+        }
+        fs::write(&self.path, self.render())
+    }
+
+    /// Words starting with `prefix`, most frequent first.
+    fn matches(&self, prefix: &str) -> Vec<&str> {
+        let mut matches: Vec<_> = self
+            .counts
+            .iter()
+            .filter(|(word, _)| word.len() > prefix.len() && word.starts_with(prefix))
+            .collect();
+        matches.sort_unstable_by(|(a, m), (b, n)| n.cmp(m).then_with(|| a.cmp(b)));
+        matches.into_iter().map(|(word, _)| word.as_str()).collect()
+    }
+
+    /// Complete the word right before `pos`.
+    ///
+    /// This returns the byte index where the word starts and the candidates, if any.
+    pub(crate) fn complete(&self, line: &str, pos: usize) -> Option<(usize, Vec<Pair>)> {
+        let head = &line[..pos];
+        let (position, token) = token_positions(head).last()?;
+        if !is_letters(token) {
+            return None;
+        }
+        let start = position.into_inner();
+        let candidates: Vec<_> = self
+            .matches(&head[start..])
+            .into_iter()
+            .map(|word| Pair {
+                display: word.to_owned(),
+                replacement: word.to_owned(),
+            })
+            .collect();
+        (!candidates.is_empty()).then_some((start, candidates))
+    }
+
+    fn render(&self) -> String {
+        let mut entries: Vec<_> = self.counts.iter().collect();
+        entries.sort_unstable_by(|(a, m), (b, n)| n.cmp(m).then_with(|| a.cmp(b)));
+        entries
+            .into_iter()
+            .map(|(word, count)| format!("{count}\t{word}\n"))
+            .collect()
+    }
+}
+
+/// Parse the contents of a vocabulary file, skipping malformed lines.
+fn parse(contents: &str) -> HashMap<String, u64> {
+    let mut counts = HashMap::new();
+    for line in contents.lines() {
+        if let Some((count, word)) = line.split_once('\t') {
+            if let Ok(count) = count.parse::<u64>() {
+                *counts.entry(word.to_owned()).or_default() += count;
+            }
+        }
+    }
+    counts
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn vocabulary() -> Vocabulary {
+        Vocabulary {
+            path: PathBuf::new(),
+            counts: HashMap::new(),
+            detector: LanguageDetector::default(),
+            tagger: None,
+        }
+    }
+
+    #[test]
+    fn learn_and_complete() {
+        let mut vocabulary = vocabulary();
+        vocabulary.learn("The meeting about the measurements is in the meeting room");
+        vocabulary.learn("Measurements were taken before the meeting");
+
+        assert_eq!(vocabulary.counts.get("meeting"), Some(&3));
+        // NOTE: stop words and short words are not learnt
+        assert_eq!(vocabulary.counts.get("the"), None);
+        assert_eq!(vocabulary.counts.get("in"), None);
+
+        assert_eq!(vocabulary.matches("me"), ["meeting", "measurements"]);
+        let (start, candidates) = vocabulary.complete("see the me", 10).unwrap();
+        assert_eq!(start, 8);
+        assert_eq!(candidates[0].replacement, "meeting");
+        assert!(vocabulary.complete("see the ", 8).is_none());
+        assert!(vocabulary.complete("meeting", 7).is_none());
+    }
+
+    #[test]
+    fn round_trip() {
+        let mut vocabulary = vocabulary();
+        vocabulary.learn("notes notes and more notes about textareas");
+        let restored = parse(&vocabulary.render());
+        assert_eq!(restored, vocabulary.counts);
+        assert_eq!(parse("2\tword\nnot a count\n1\tword\n")["word"], 3);
+    }
+}
@@ -0,0 +1,179 @@
+//! Word boundaries of [`Textarea`](crate::Textarea) and [`TextBuffer`].
+//!
+//! With the `tagger` feature, words follow the token boundaries of `universal_tagger`,
+//! so that they work across scripts. Otherwise, they are the words of
+//! [UAX #29](https://www.unicode.org/reports/tr29/).
+
+use std::ops::Range;
+
+use rustyline::{Cmd, KeyCode, KeyEvent, Modifiers, RepeatCount};
+#[cfg(not(feature = "tagger"))]
+use unicode_segmentation::UnicodeSegmentation;
+#[cfg(feature = "tagger")]
+use universal_tagger::{token_positions, Token};
+
+use crate::{motion::motion, TextBuffer};
+
+/// Whether `token` is a word made of letters, as opposed to numbers.
+#[cfg(feature = "tagger")]
+pub(crate) const fn is_letters(token: Token<'_>) -> bool {
+    matches!(
+        token,
+        Token::Letter(_)
+            | Token::LetterOrMark(_)
+            | Token::LetterOrOther(_)
+            | Token::LetterOrPunctuation(_)
+    )
+}
+
+/// Whether `token` is part of a word, as opposed to spaces, punctuation and symbols.
+#[cfg(feature = "tagger")]
+const fn is_word(token: Token<'_>) -> bool {
+    is_letters(token)
+        || matches!(
+            token,
+            Token::LetterOrNumber(_)
+                | Token::Number(_)
+                | Token::NumberOrMark(_)
+                | Token::NumberOrOther(_)
+                | Token::NumberOrPunctuation(_)
+        )
+}
+
+/// Byte ranges of the words in `line`.
+#[cfg(feature = "tagger")]
+fn words(line: &str) -> impl Iterator<Item = Range<usize>> + '_ {
+    token_positions(line).filter_map(|(position, token)| {
+        let start = position.into_inner();
+        is_word(token).then(|| start..start + token.as_ref().len())
+    })
+}
+
+/// Byte ranges of the words in `line`.
+#[cfg(not(feature = "tagger"))]
+fn words(line: &str) -> impl Iterator<Item = Range<usize>> + '_ {
+    line.unicode_word_indices()
+        .map(|(start, word)| start..start + word.len())
+}
+
+/// The end of the `n`th word ending after `pos`.
+pub(crate) fn next_word_end(line: &str, pos: usize, n: RepeatCount) -> Option<usize> {
+    words(line)
+        .filter(|word| word.end > pos)
+        .nth(n.saturating_sub(1))
+        .map(|word| word.end)
+}
+
+/// The start of the `n`th word starting before `pos`, backwards.
+pub(crate) fn previous_word_start(line: &str, pos: usize, n: RepeatCount) -> Option<usize> {
+    let starts: Vec<_> = words(line)
+        .map(|word| word.start)
+        .take_while(|&start| start < pos)
+        .collect();
+    starts.into_iter().rev().nth(n.saturating_sub(1))
+}
+
+/// Handle a key event, returning [`None`] if it should get its default behaviour.
+///
+/// The target of word motions is found by a [`TextBuffer`] over `line`,
+/// so that both move and kill the same words.
+pub(crate) fn handle(key: KeyEvent, n: RepeatCount, line: &str, pos: usize) -> Option<Cmd> {
+    use KeyCode as K;
+    use Modifiers as M;
+
+    let (forward, kill) = match key {
+        KeyEvent(K::Char('f' | 'F'), M::ALT) | KeyEvent(K::Right, M::CTRL) => (true, false),
+        KeyEvent(K::Char('b' | 'B'), M::ALT) | KeyEvent(K::Left, M::CTRL) => (false, false),
+        KeyEvent(K::Char('d' | 'D'), M::ALT) => (true, true),
+        KeyEvent(K::Char('W'), M::CTRL) | KeyEvent(K::Backspace, M::ALT) => (false, true),
+        _ => return None,
+    };
+    let mut buffer = TextBuffer::from(line);
+    buffer.set_cursor(pos);
+    if forward {
+        buffer.move_word_forward(n);
+    } else {
+        buffer.move_word_backward(n);
+    }
+    let target = buffer.cursor();
+    Some(if target == pos {
+        Cmd::Noop
+    } else {
+        motion(line, pos, target, kill)
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use rustyline::{CharSearch, Movement};
+
+    use super::*;
+
+    #[cfg(feature = "tagger")]
+    #[test]
+    fn word_boundaries() {
+        let line = "naïve café, 東京 مرحبا";
+        let found: Vec<_> = words(line).map(|word| &line[word]).collect();
+        assert_eq!(found, ["naïve", "café", "東京", "مرحبا"]);
+
+        assert_eq!(next_word_end(line, 0, 1), Some(6));
+        assert_eq!(next_word_end(line, 6, 1), Some(12));
+        assert_eq!(next_word_end(line, 6, 2), Some(20));
+        assert_eq!(previous_word_start(line, line.len(), 1), Some(21));
+        assert_eq!(previous_word_start(line, 12, 1), Some(7));
+        assert_eq!(previous_word_start(line, 7, 1), Some(0));
+        assert_eq!(previous_word_start(line, 0, 1), None);
+    }
+
+    #[test]
+    fn char_searches() {
+        let line = "ab, cd, ef";
+        assert_eq!(
+            handle(KeyEvent::alt('f'), 1, line, 0),
+            Some(Cmd::Move(Movement::ViCharSearch(
+                1,
+                CharSearch::Forward(',')
+            )))
+        );
+        // NOTE: the comma after "ab" is skipped to reach the one after "cd"
+        assert_eq!(
+            handle(KeyEvent::alt('d'), 2, line, 0),
+            Some(Cmd::Kill(Movement::ViCharSearch(
+                2,
+                CharSearch::ForwardBefore(',')
+            )))
+        );
+        assert_eq!(
+            handle(KeyEvent::alt('f'), 1, line, 8),
+            Some(Cmd::Move(Movement::EndOfBuffer))
+        );
+        assert_eq!(
+            handle(KeyEvent::alt('f'), 1, line, line.len()),
+            Some(Cmd::Noop)
+        );
+        assert_eq!(
+            handle(KeyEvent::alt('b'), 3, line, 4),
+            Some(Cmd::Move(Movement::ViCharSearch(
+                1,
+                CharSearch::Backward('a')
+            )))
+        );
+
+        let line = "x aba";
+        assert_eq!(
+            handle(KeyEvent::ctrl('W'), 1, line, 5),
+            Some(Cmd::Kill(Movement::ViCharSearch(
+                2,
+                CharSearch::Backward('a')
+            )))
+        );
+        assert_eq!(
+            handle(KeyEvent::alt('b'), 2, line, 5),
+            Some(Cmd::Move(Movement::ViCharSearch(
+                1,
+                CharSearch::Backward('x')
+            )))
+        );
+        assert_eq!(handle(KeyEvent::alt('x'), 1, line, 0), None);
+    }
+}
@@ -0,0 +1,53 @@
+//! Throughput of tokenization, which coalesces adjacent words of the same kind.
+//!
+//! Compare two revisions with `cargo bench --bench tokenize -- --save-baseline before`
+//! on the first, then `cargo bench --bench tokenize -- --baseline before` on the second.
+
+use std::hint::black_box;
+
+use criterion::{criterion_group, criterion_main, Criterion, Throughput};
+use universal_tagger::{token_positions, Language, Tokenizer};
+
+/// Prose in several languages, with entities, numbers and emoji.
+const PROSE: &str = "Colorless green ideas sleep furiously. Dr. Smith paid $1,000.50 for \
+    https://example.com/a?b=c on 2026-10-17, didn't he? Ceci n'est pas une pipe... \
+    ¿¡Qué pasa!? Der Donaudampfschifffahrtskapitän fährt um 17 Uhr. 我们是学生。 \
+    So 👩‍💻👍🏽 — «ça va», said @user #rust.\n\n";
+
+/// Runs of whitespace, punctuation and symbols, which are coalesced the most.
+const RUNS: &str = "Wait...!!!   What?!?   $$$ +++ ***\t\t\n\n---   ≥≤≠ ... !!! ???   \
+    (((nested))) ,,, ;;; ::: ''' \"\"\"   ~~~ ^^^ ___ |||   \n";
+
+/// Lines ending in entities, each one a sentence holding an entity.
+const ENTITIES: &str = "GET 200 in 3 ms from path /api/v1/items\n";
+
+fn tokenize(c: &mut Criterion) {
+    let mut group = c.benchmark_group("token_positions");
+    for (name, text) in [
+        ("prose", PROSE.repeat(64)),
+        ("runs", RUNS.repeat(64)),
+        ("entities", ENTITIES.repeat(4096)),
+        (
+            "long_runs",
+            ["!", "-", "$", "."].map(|c| c.repeat(16 * 1024)).join(" "),
+        ),
+    ] {
+        group.throughput(Throughput::Bytes(text.len() as u64));
+        group.bench_function(name, |b| {
+            b.iter(|| token_positions(black_box(&text)).count());
+        });
+    }
+    group.finish();
+
+    let text = PROSE.repeat(64);
+    let mut group = c.benchmark_group("tokenizer");
+    group.throughput(Throughput::Bytes(text.len() as u64));
+    group.bench_function("english", |b| {
+        let tokenizer = Tokenizer::new(Language::Eng);
+        b.iter(|| tokenizer.token_positions(black_box(&text)).count());
+    });
+    group.finish();
+}
+
+criterion_group!(benches, tokenize);
+criterion_main!(benches);
@@ -0,0 +1,76 @@
+use crate::Language;
+
+/// Common abbreviations, lowercase and without their final period.
+///
+/// Each list is sorted, so that it can be binary searched.
+/// Abbreviations that are also common words (such as "no" or "min") are left out,
+/// as they would hide the end of sentences like "The answer is no."
+#[inline]
+#[must_use]
+pub const fn get(lang: Language) -> &'static [&'static str] {
+    match lang {
+        #[cfg(feature = "german")]
+        Language::Deu => &[
+            "abs", "bzw", "ca", "d.h", "dr", "evtl", "ggf", "hr", "inkl", "max", "mio", "mrd",
+            "nr", "str", "u.a", "usw", "vgl", "z.b", "z.t",
+        ],
+        #[cfg(feature = "english")]
+        Language::Eng => &[
+            "a.m", "al", "approx", "apr", "assn", "aug", "ave", "blvd", "capt", "cf", "co", "col",
+            "corp", "dec", "dept", "dr", "e.g", "etc", "feb", "fig", "gov", "i.e", "inc", "jan",
+            "jr", "jul", "jun", "lt", "ltd", "mr", "mrs", "ms", "mt", "nov", "oct", "p.m", "ph.d",
+            "prof", "rd", "rev", "sep", "sept", "sgt", "sr", "st", "u.k", "u.s", "u.s.a", "vol",
+            "vs",
+        ],
+        #[cfg(feature = "esperanto")]
+        Language::Epo => &["ekz", "k.t.p", "p.k", "t.e"],
+        #[cfg(feature = "french")]
+        Language::Fra => &[
+            "av", "bd", "cf", "dr", "etc", "ex", "mlle", "mm", "mme", "p.ex", "st", "ste",
+        ],
+        #[cfg(feature = "indonesian")]
+        Language::Ind => &["dll", "dr", "dsb", "hlm", "prof", "sdr", "tsb", "yth"],
+        #[cfg(feature = "italian")]
+        Language::Ita => &["avv", "dott", "ecc", "es", "ing", "pag", "prof", "sig"],
+        #[cfg(feature = "portuguese")]
+        Language::Por => &[
+            "av", "dr", "dra", "etc", "ex", "p.ex", "prof", "pág", "sr", "sra", "srta",
+        ],
+        #[cfg(feature = "russian")]
+        Language::Rus => &[
+            "г", "гг", "др", "и.о", "им", "млн", "млрд", "н.э", "пр", "проф", "см", "стр", "т.д",
+            "т.е", "т.к", "т.п", "тыс", "ул",
+        ],
+        #[cfg(feature = "spanish")]
+        Language::Spa => &[
+            "av", "dr", "dra", "ej", "etc", "p.ej", "pág", "sr", "sra", "srta", "ud", "uds", "vs",
+        ],
+        #[cfg(feature = "turkish")]
+        Language::Tur => &["doç", "dr", "prof", "sn", "st", "vb", "vs", "örn"],
+        // NOTE: these scripts don't mark abbreviations with periods
+        #[allow(unreachable_patterns)]
+        _ => &[],
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use strum::IntoEnumIterator;
+
+    use super::*;
+
+    #[test]
+    fn sorted_lowercase_lists() {
+        for lang in Language::iter() {
+            let abbreviations = get(lang);
+            assert!(
+                abbreviations.windows(2).all(|pair| pair[0] < pair[1]),
+                "{lang:?}"
+            );
+            for abbreviation in abbreviations {
+                assert_eq!(abbreviation.to_lowercase(), *abbreviation);
+                assert!(!abbreviation.ends_with('.'));
+            }
+        }
+    }
+}
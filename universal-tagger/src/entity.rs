@@ -0,0 +1,244 @@
+use std::{
+    net::{IpAddr, SocketAddr},
+    ops::Range,
+};
+
+use crate::{Number, Token};
+
+/// Returns the length in bytes of the entity found at the start of the rest of a text, if any.
+type Recognize = fn(&str) -> Option<usize>;
+
+/// Wraps an entity into its kind of token.
+type Kind = fn(&str) -> Token<'_>;
+
+/// Recognizers, tried in order on text that starts an entity.
+const RECOGNIZERS: [(Recognize, Kind); 7] = [
+    (url, |word| Token::Url(word)),
+    (email, |word| Token::Email(word)),
+    (ip_address, |word| Token::IpAddress(word)),
+    (path, |word| Token::Path(word)),
+    (mention, |word| Token::Mention(word)),
+    (hashtag, |word| Token::Hashtag(word)),
+    (crate::number::recognize, |word| {
+        Token::Number(Number::parse(word, None).unwrap_or_else(|| Number::other(word)))
+    }),
+];
+
+/// Classify `word` as an entity, if it is one as a whole.
+#[inline]
+pub(crate) fn classify(word: &str) -> Option<Token<'_>> {
+    RECOGNIZERS
+        .iter()
+        .find_map(|(recognize, token)| (recognize(word)? == word.len()).then(|| token(word)))
+}
+
+/// Byte ranges of the entities of `text`, in order.
+///
+/// Entities start at the beginning of the text, after whitespace or after an opening bracket or quote,
+/// and never include whitespace, except for the no-break spaces grouping the digits of numbers.
+pub(crate) fn find(text: &str) -> impl Iterator<Item = Range<usize>> + '_ {
+    let mut start = 0;
+    let mut previous: Option<char> = None;
+    std::iter::from_fn(move || {
+        while let Some(c) = text[start..].chars().next() {
+            let candidate = match previous {
+                Some(previous) => previous.is_whitespace() || is_opening(previous),
+                None => true,
+            };
+            if candidate {
+                if let Some(len) = RECOGNIZERS
+                    .iter()
+                    .find_map(|(recognize, _)| recognize(&text[start..]))
+                {
+                    let range = start..start + len;
+                    start = range.end;
+                    previous = text[..start].chars().next_back();
+                    return Some(range);
+                }
+            }
+            start += c.len_utf8();
+            previous = Some(c);
+        }
+        None
+    })
+}
+
+#[inline]
+fn is_opening(c: char) -> bool {
+    matches!(c, '(' | '[' | '{' | '<' | '"' | '\'' | '“' | '‘' | '«')
+}
+
+/// The start of `text` up to the first whitespace.
+#[inline]
+fn chunk(text: &str) -> &str {
+    text.split(char::is_whitespace).next().unwrap_or_default()
+}
+
+/// Strip the punctuation that ends a sentence or a clause from `chunk`,
+/// along with closing brackets that are not part of it.
+fn trim_end(chunk: &str) -> &str {
+    let mut chunk = chunk;
+    while let Some(c) = chunk.chars().next_back() {
+        let unbalanced = |open, close| chunk.matches(open).count() < chunk.matches(close).count();
+        let trailing = match c {
+            '.' | ',' | ';' | ':' | '!' | '?' | '"' | '\'' | '”' | '’' | '»' | '>' => true,
+            ')' => unbalanced('(', ')'),
+            ']' => unbalanced('[', ']'),
+            '}' => unbalanced('{', '}'),
+            _ => false,
+        };
+        if !trailing {
+            break;
+        }
+        chunk = &chunk[..chunk.len() - c.len_utf8()];
+    }
+    chunk
+}
+
+/// A URL with a scheme, as in `https://example.com`, or starting with `www.`.
+fn url(text: &str) -> Option<usize> {
+    let chunk = trim_end(chunk(text));
+    let rest = match chunk.split_once("://") {
+        Some((scheme, rest))
+            if scheme.starts_with(|c: char| c.is_ascii_alphabetic())
+                && scheme
+                    .chars()
+                    .all(|c| c.is_ascii_alphanumeric() || matches!(c, '+' | '.' | '-')) =>
+        {
+            rest
+        }
+        _ => chunk
+            .get(..4)
+            .filter(|www| www.eq_ignore_ascii_case("www."))
+            .map(|_| &chunk[4..])?,
+    };
+    rest.starts_with(char::is_alphanumeric)
+        .then_some(chunk.len())
+}
+
+/// An email address, as in `user@example.com`.
+fn email(text: &str) -> Option<usize> {
+    let chunk = trim_end(chunk(text));
+    let (local, domain) = chunk.split_once('@')?;
+    let local = !local.is_empty()
+        && local
+            .chars()
+            .all(|c| c.is_alphanumeric() || matches!(c, '.' | '_' | '%' | '+' | '-'));
+    let labels: Vec<_> = domain.split('.').collect();
+    let domain = labels.len() >= 2
+        && labels.iter().all(|label| {
+            !label.is_empty() && label.chars().all(|c| c.is_alphanumeric() || c == '-')
+        })
+        && labels
+            .last()
+            .is_some_and(|tld| tld.chars().count() >= 2 && tld.chars().all(char::is_alphabetic));
+    (local && domain).then_some(chunk.len())
+}
+
+/// An IPv4 or IPv6 address, possibly with a port, as in `127.0.0.1:8080` or `[::1]:80`.
+fn ip_address(text: &str) -> Option<usize> {
+    let chunk = trim_end(chunk(text));
+    // NOTE: "::" alone is a valid address, but hardly meant as one
+    let address = chunk.contains(|c: char| c.is_ascii_hexdigit())
+        && (chunk.parse::<IpAddr>().is_ok() || chunk.parse::<SocketAddr>().is_ok());
+    address.then_some(chunk.len())
+}
+
+/// A file path, as in `/etc/hosts`, `./notes.txt`, `~/notes` or `C:\Users`.
+fn path(text: &str) -> Option<usize> {
+    let chunk = trim_end(chunk(text));
+    let rest = ["~/", "./", "../", ".\\", "..\\"]
+        .iter()
+        .find_map(|prefix| chunk.strip_prefix(prefix))
+        .or_else(|| {
+            // NOTE: a single slash, as in "/etc", is too often something else
+            let rest = chunk.strip_prefix('/')?;
+            rest.contains('/').then_some(rest)
+        })
+        .or_else(|| {
+            let mut chars = chunk.chars();
+            match (chars.next(), chars.next(), chars.next()) {
+                (Some(drive), Some(':'), Some('\\')) if drive.is_ascii_alphabetic() => {
+                    Some(&chunk[3..])
+                }
+                _ => None,
+            }
+        })?;
+    rest.starts_with(|c: char| c.is_alphanumeric() || matches!(c, '.' | '_' | '-'))
+        .then_some(chunk.len())
+}
+
+/// The length of the name after a `sigil`, such as `#` in `#rust`.
+fn name_after(sigil: char, text: &str) -> Option<usize> {
+    let name = text.strip_prefix(sigil)?;
+    let len: usize = name
+        .chars()
+        .take_while(|&c| c.is_alphanumeric() || c == '_')
+        .map(char::len_utf8)
+        .sum();
+    (len > 0).then_some(sigil.len_utf8() + len)
+}
+
+/// A mention of a user, as in `@user`.
+fn mention(text: &str) -> Option<usize> {
+    name_after('@', text)
+}
+
+/// A hashtag, as in `#rust`, but not a number such as `#1`.
+fn hashtag(text: &str) -> Option<usize> {
+    let len = name_after('#', text)?;
+    text[..len].contains(char::is_alphabetic).then_some(len)
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+
+    fn entities(text: &str) -> Vec<&str> {
+        find(text).map(|range| &text[range]).collect()
+    }
+
+    #[test]
+    fn find_entities() {
+        assert_eq!(
+            entities(
+                "See https://example.com/a?b=c, (mail user@example.com) or ping @bob_1 \
+                 at 10.0.0.1:8080 or [::1]. Logs: /var/log/syslog, ~/notes #rust #1 a@b"
+            ),
+            [
+                "https://example.com/a?b=c",
+                "user@example.com",
+                "@bob_1",
+                "10.0.0.1:8080",
+                "::1",
+                "/var/log/syslog",
+                "~/notes",
+                "#rust",
+            ]
+        );
+        assert_eq!(
+            entities("(www.example.com/wiki/Rust_(language)) and/or 3.14"),
+            ["www.example.com/wiki/Rust_(language)", "3.14"]
+        );
+        assert_eq!(
+            entities("It fell -2.5% to 1\u{a0}000 on the 3rd, page 10-12"),
+            ["-2.5%", "1\u{a0}000", "3rd", "10"]
+        );
+    }
+
+    #[test]
+    fn classify_entities() {
+        assert_eq!(classify("C:\\Users"), Some(Token::Path("C:\\Users")));
+        assert_eq!(classify("::1"), Some(Token::IpAddress("::1")));
+        assert_eq!(classify("@bob"), Some(Token::Mention("@bob")));
+        assert_eq!(classify("::"), None);
+        assert_eq!(classify("https://"), None);
+        assert_eq!(classify("https://example.com."), None);
+        assert_eq!(classify("#bob!"), None);
+        assert!(
+            matches!(classify("1e3"), Some(Token::Number(number)) if number.value == Some(1000.0))
+        );
+    }
+}
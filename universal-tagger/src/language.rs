@@ -3,6 +3,7 @@ use strum::EnumIter;
 /// Language codes following the [ISO 639-3](https://en.wikipedia.org/wiki/ISO_639-3) standard.
 #[non_exhaustive]
 #[derive(Clone, Copy, Debug, EnumIter, PartialEq, Eq, PartialOrd, Ord)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum Language {
     /// العربية (Arabic).
     #[cfg(feature = "arabic")]
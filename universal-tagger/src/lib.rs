@@ -2,14 +2,42 @@
 //!
 //! This is in early stages of development.
 
+#![forbid(unsafe_code)]
+
+mod abbreviations;
+mod entity;
 mod language;
 mod language_detection;
+mod number;
+mod number_words;
+mod owned;
+mod punkt;
+mod segmentation;
+mod sentence;
+mod span;
 mod stop_words;
+mod stream;
 mod tagger;
+#[cfg(feature = "temporal")]
+mod temporal;
+mod tokenizer;
 mod unicode_segmentation;
 
 pub use language::Language;
 pub use language_detection::LanguageDetector;
-pub use tagger::Tagger;
+pub use number::{Number, NumberKind};
+pub use number_words::{number_words, NumberWords};
+pub use owned::{NumberBuf, TaggedToken, TokenBuf, TokenInfoBuf};
+pub use punkt::Punkt;
+pub use sentence::{sentences, Sentence};
+pub use span::{token_spans, Offset, Span, TokenInfo};
+pub use stream::{token_stream, StreamTokenizer, TokenStream};
+pub use tagger::{Tag, Tagger};
+#[cfg(feature = "temporal")]
+pub use temporal::{Temporal, TemporalValue};
+pub use tokenizer::Tokenizer;
+
+#[cfg(feature = "temporal")]
+pub use chrono;
 
 pub use crate::unicode_segmentation::{token_positions, Token};
@@ -0,0 +1,335 @@
+use crate::Language;
+
+/// Characters that may separate groups of digits, or the integer part from the fraction.
+const SEPARATORS: [char; 9] = [
+    '.',
+    ',',
+    '\'',
+    '’',
+    '\u{a0}',
+    '\u{202f}',
+    '\u{2009}',
+    ARABIC_DECIMAL,
+    ARABIC_GROUP,
+];
+
+const ARABIC_DECIMAL: char = '\u{66b}';
+const ARABIC_GROUP: char = '\u{66c}';
+
+const PERCENT_SIGNS: [char; 2] = ['%', '\u{66a}'];
+
+/// Suffixes of ordinals, longest first where one starts another.
+const ORDINAL_SUFFIXES: [&str; 15] = [
+    "st", "nd", "rd", "th", "º", "ª", "ème", "eme", "er", "re", "e", "-го", "-й", "-я", "-е",
+];
+
+/// What a number expresses.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum NumberKind {
+    Integer,
+    Decimal,
+    /// A number with an exponent, such as "1.5e-3".
+    Scientific,
+    /// A number followed by a percent sign, such as "50%".
+    Percentage,
+    /// A rank, such as "1st" or "2º".
+    Ordinal,
+    /// Numeric characters without a decimal value, such as "½" or "Ⅻ".
+    Other,
+}
+
+/// A number, as written in a text.
+#[derive(Clone, Copy, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Number<'text> {
+    pub text: &'text str,
+    /// The value as written, so that "50%" is 50 and "2nd" is 2.
+    ///
+    /// This is `None` for numbers of [`NumberKind::Other`].
+    pub value: Option<f64>,
+    pub kind: NumberKind,
+}
+
+impl PartialEq for Number<'_> {
+    #[inline]
+    fn eq(&self, other: &Self) -> bool {
+        self.text == other.text
+            && self.kind == other.kind
+            && self.value.map(f64::to_bits) == other.value.map(f64::to_bits)
+    }
+}
+
+impl Eq for Number<'_> {}
+
+impl<'text> Number<'text> {
+    /// Parse the whole of `text` as a number.
+    ///
+    /// Signs, groups of digits, a fraction, an exponent, and a percent sign or an ordinal suffix
+    /// are understood, written with any of the ASCII, Arabic-Indic, Devanagari or Bengali digits.
+    /// Groups and the fraction are told apart following the decimal separator of `lang`,
+    /// or guessed from the separators themselves when no language is given,
+    /// so that "1,000" is a thousand in English, but one in German.
+    #[must_use]
+    pub fn parse(text: &'text str, lang: Option<Language>) -> Option<Self> {
+        let (len, value, kind) = scan(text, lang.map(decimal_separator))?;
+        (len == text.len()).then_some(Self {
+            text,
+            value: Some(value),
+            kind,
+        })
+    }
+
+    /// Numeric characters without a decimal value.
+    #[inline]
+    pub(crate) const fn other(text: &'text str) -> Self {
+        Self {
+            text,
+            value: None,
+            kind: NumberKind::Other,
+        }
+    }
+}
+
+/// The separator of the fraction of numbers written in `lang`.
+#[inline]
+const fn decimal_separator(lang: Language) -> char {
+    match lang {
+        #[cfg(feature = "german")]
+        Language::Deu => ',',
+        #[cfg(feature = "esperanto")]
+        Language::Epo => ',',
+        #[cfg(feature = "french")]
+        Language::Fra => ',',
+        #[cfg(feature = "indonesian")]
+        Language::Ind => ',',
+        #[cfg(feature = "italian")]
+        Language::Ita => ',',
+        #[cfg(feature = "portuguese")]
+        Language::Por => ',',
+        #[cfg(feature = "russian")]
+        Language::Rus => ',',
+        #[cfg(feature = "spanish")]
+        Language::Spa => ',',
+        #[cfg(feature = "turkish")]
+        Language::Tur => ',',
+        #[allow(unreachable_patterns)]
+        _ => '.',
+    }
+}
+
+/// The length in bytes of the number at the start of `text`, if any.
+#[inline]
+pub(crate) fn recognize(text: &str) -> Option<usize> {
+    scan(text, None).map(|(len, _, _)| len)
+}
+
+/// The value of a decimal digit in any supported digit system.
+#[inline]
+fn digit(c: char) -> Option<u32> {
+    let zero = match c {
+        '0'..='9' => '0',
+        '\u{660}'..='\u{669}' => '\u{660}',
+        '\u{6f0}'..='\u{6f9}' => '\u{6f0}',
+        '\u{966}'..='\u{96f}' => '\u{966}',
+        '\u{9e6}'..='\u{9ef}' => '\u{9e6}',
+        '\u{ff10}'..='\u{ff19}' => '\u{ff10}',
+        _ => return None,
+    };
+    Some(c as u32 - zero as u32)
+}
+
+/// Read the digits at the start of `text` as ASCII, along with their length in bytes.
+fn digits(text: &str) -> (String, usize) {
+    let mut ascii = String::new();
+    let mut len = 0;
+    for (c, value) in text.chars().map_while(|c| Some((c, digit(c)?))) {
+        ascii.extend(char::from_digit(value, 10));
+        len += c.len_utf8();
+    }
+    (ascii, len)
+}
+
+/// Whether the last of `separators` starts the fraction, or `None` if they make no sense.
+fn is_decimal_point(groups: &[String], separators: &[char], decimal: Option<char>) -> Option<bool> {
+    let Some(&last) = separators.last() else {
+        return Some(false);
+    };
+    let point = match decimal {
+        _ if last == ARABIC_DECIMAL => true,
+        _ if last == ARABIC_GROUP => false,
+        Some(decimal) => last == decimal,
+        // NOTE: "1,000" is a thousand and "1,5" is one and a half, but "1.000" is one
+        None if separators.len() == 1 && last == ',' => groups[1].len() != 3,
+        None if separators.len() == 1 => last == '.',
+        None => separators.iter().any(|&separator| separator != last),
+    };
+
+    let (integer, separators) = if point {
+        (
+            &groups[..groups.len() - 1],
+            &separators[..separators.len() - 1],
+        )
+    } else {
+        (groups, separators)
+    };
+    let grouped = separators.iter().all(|&separator| {
+        separator == separators[0]
+            && separator != ARABIC_DECIMAL
+            && Some(separator) != decimal
+            && !(point && separator == last)
+    });
+    // NOTE: groups of two are allowed before the last for Indian numbering, as in "1,00,000"
+    let sized = match integer {
+        [_] => true,
+        [first, middle @ .., last] => {
+            (1..=3).contains(&first.len())
+                && middle.iter().all(|group| (2..=3).contains(&group.len()))
+                && last.len() == 3
+        }
+        [] => false,
+    };
+    (grouped && sized).then_some(point)
+}
+
+/// Read the number at the start of `text`, returning its length in bytes, value and kind.
+///
+/// The decimal separator is guessed if not given.
+fn scan(text: &str, decimal: Option<char>) -> Option<(usize, f64, NumberKind)> {
+    let mut rest = text;
+    let mut ascii = String::new();
+    let signed = if let Some(tail) = rest.strip_prefix(['-', '−']) {
+        ascii.push('-');
+        rest = tail;
+        true
+    } else if let Some(tail) = rest.strip_prefix('+') {
+        rest = tail;
+        true
+    } else {
+        false
+    };
+
+    let mut groups = Vec::new();
+    let mut separators = Vec::new();
+    loop {
+        let (group, len) = digits(rest);
+        if group.is_empty() {
+            return None;
+        }
+        groups.push(group);
+        rest = &rest[len..];
+
+        let mut chars = rest.chars();
+        match (chars.next(), chars.next()) {
+            (Some(separator), Some(next))
+                if SEPARATORS.contains(&separator) && digit(next).is_some() =>
+            {
+                separators.push(separator);
+                rest = &rest[separator.len_utf8()..];
+            }
+            _ => break,
+        }
+    }
+    let point = is_decimal_point(&groups, &separators, decimal)?;
+    let fraction = if point { groups.pop() } else { None };
+    ascii.extend(groups);
+    let mut kind = NumberKind::Integer;
+    if let Some(fraction) = fraction {
+        ascii.push('.');
+        ascii.push_str(&fraction);
+        kind = NumberKind::Decimal;
+    }
+
+    if let Some(tail) = rest.strip_prefix(['e', 'E']) {
+        let (sign, tail) = match tail.strip_prefix(['-', '−']) {
+            Some(tail) => ("-", tail),
+            None => ("", tail.strip_prefix('+').unwrap_or(tail)),
+        };
+        let (exponent, len) = digits(tail);
+        if !exponent.is_empty() {
+            ascii.push('e');
+            ascii.push_str(sign);
+            ascii.push_str(&exponent);
+            rest = &tail[len..];
+            kind = NumberKind::Scientific;
+        }
+    }
+
+    if let Some(tail) = rest.strip_prefix(PERCENT_SIGNS) {
+        rest = tail;
+        kind = NumberKind::Percentage;
+    } else if kind == NumberKind::Integer && !signed {
+        let suffix = ORDINAL_SUFFIXES.into_iter().find(|suffix| {
+            rest.get(..suffix.len()).is_some_and(|head| {
+                head.eq_ignore_ascii_case(suffix)
+                    && !rest[suffix.len()..].starts_with(char::is_alphanumeric)
+            })
+        });
+        if let Some(suffix) = suffix {
+            rest = &rest[suffix.len()..];
+            kind = NumberKind::Ordinal;
+        }
+    }
+
+    // NOTE: "12abc" is not a number followed by a word, and "1️⃣" is a keycap emoji
+    if rest.starts_with(|c: char| c.is_alphanumeric() || matches!(c, '\u{fe0f}' | '\u{20e3}')) {
+        return None;
+    }
+    let value = ascii.parse().ok()?;
+    Some((text.len() - rest.len(), value, kind))
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+
+    fn value(text: &str, lang: Option<Language>) -> Option<(f64, NumberKind)> {
+        let number = Number::parse(text, lang)?;
+        Some((number.value?, number.kind))
+    }
+
+    #[test]
+    fn parse_numbers() {
+        use NumberKind::{Decimal, Integer, Ordinal, Percentage, Scientific};
+
+        assert_eq!(value("42", None), Some((42.0, Integer)));
+        assert_eq!(value("-1,000.50", None), Some((-1000.5, Decimal)));
+        assert_eq!(value("1.000,50", None), Some((1000.5, Decimal)));
+        assert_eq!(value("1,5", None), Some((1.5, Decimal)));
+        assert_eq!(value("1,0000", None), Some((1.0, Decimal)));
+        assert_eq!(value("1,000", None), Some((1000.0, Integer)));
+        assert_eq!(value("1.000.000", None), Some((1_000_000.0, Integer)));
+        assert_eq!(value("1,00,000", None), Some((100_000.0, Integer)));
+        assert_eq!(value("1\u{a0}000", None), Some((1000.0, Integer)));
+        assert_eq!(value("+1.5e-3", None), Some((0.0015, Scientific)));
+        assert_eq!(value("50%", None), Some((50.0, Percentage)));
+        assert_eq!(value("1st", None), Some((1.0, Ordinal)));
+        assert_eq!(value("2º", None), Some((2.0, Ordinal)));
+        assert_eq!(value("2e", None), Some((2.0, Ordinal)));
+        assert_eq!(value("١٢٣٫٥", None), Some((123.5, Decimal)));
+        assert_eq!(value("१२३", None), Some((123.0, Integer)));
+        assert_eq!(value("১০", None), Some((10.0, Integer)));
+
+        for text in [
+            "inf", "NaN", "1.2.3", "1,000,0", "12abc", "-", "1,", "-1st", "½",
+        ] {
+            assert_eq!(Number::parse(text, None), None, "{text:?}");
+        }
+        assert_eq!(recognize("5. Then"), Some(1));
+        assert_eq!(recognize("3.14, or"), Some(4));
+    }
+
+    #[cfg(all(feature = "english", feature = "german"))]
+    #[test]
+    fn parse_per_language() {
+        use NumberKind::{Decimal, Integer};
+
+        assert_eq!(value("1,000", Some(Language::Eng)), Some((1000.0, Integer)));
+        assert_eq!(value("1,000", Some(Language::Deu)), Some((1.0, Decimal)));
+        assert_eq!(value("1.000", Some(Language::Deu)), Some((1000.0, Integer)));
+        assert_eq!(value("1.000", Some(Language::Eng)), Some((1.0, Decimal)));
+        assert_eq!(value("1.000,5", Some(Language::Eng)), None);
+    }
+}
@@ -0,0 +1,1010 @@
+use std::ops::Range;
+
+use crate::{span::Spanner, Language, Span, Token, Tokenizer};
+
+/// What a number word contributes to the number it is part of.
+// NOTE: not every language makes use of every variant
+#[allow(dead_code)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum Value {
+    /// Adds to the number, as "three" or "twenty".
+    Unit(u64),
+    /// Adds ten to the unit before it, as "belas" in Indonesian "dua belas".
+    Teen,
+    /// Multiplies the units before it, as "hundred" in "three hundred".
+    Multiplier(u64),
+    /// Multiplies everything before it, as "thousand" in "three hundred thousand".
+    Scale(u64),
+    /// Joins number words, as "and" in "one hundred and five".
+    Connector,
+}
+
+/// The number words of `lang`, lowercase.
+#[allow(clippy::too_many_lines)]
+const fn words(lang: Language) -> &'static [(&'static str, Value)] {
+    #[allow(unused_imports)]
+    use Value::{Connector, Multiplier, Scale, Teen, Unit};
+
+    match lang {
+        #[cfg(feature = "arabic")]
+        Language::Ara => &[
+            ("صفر", Unit(0)),
+            ("واحد", Unit(1)),
+            ("واحدة", Unit(1)),
+            ("أحد", Unit(1)),
+            ("إحدى", Unit(1)),
+            ("اثنان", Unit(2)),
+            ("اثنين", Unit(2)),
+            ("اثنا", Unit(2)),
+            ("اثنتان", Unit(2)),
+            ("ثلاثة", Unit(3)),
+            ("ثلاث", Unit(3)),
+            ("أربعة", Unit(4)),
+            ("أربع", Unit(4)),
+            ("خمسة", Unit(5)),
+            ("خمس", Unit(5)),
+            ("ستة", Unit(6)),
+            ("ست", Unit(6)),
+            ("سبعة", Unit(7)),
+            ("سبع", Unit(7)),
+            ("ثمانية", Unit(8)),
+            ("ثماني", Unit(8)),
+            ("ثمان", Unit(8)),
+            ("تسعة", Unit(9)),
+            ("تسع", Unit(9)),
+            ("عشرة", Unit(10)),
+            ("عشر", Teen),
+            ("عشرون", Unit(20)),
+            ("عشرين", Unit(20)),
+            ("ثلاثون", Unit(30)),
+            ("ثلاثين", Unit(30)),
+            ("أربعون", Unit(40)),
+            ("أربعين", Unit(40)),
+            ("خمسون", Unit(50)),
+            ("خمسين", Unit(50)),
+            ("ستون", Unit(60)),
+            ("ستين", Unit(60)),
+            ("سبعون", Unit(70)),
+            ("سبعين", Unit(70)),
+            ("ثمانون", Unit(80)),
+            ("ثمانين", Unit(80)),
+            ("تسعون", Unit(90)),
+            ("تسعين", Unit(90)),
+            ("مائة", Multiplier(100)),
+            ("مئة", Multiplier(100)),
+            ("مائتان", Unit(200)),
+            ("مئتان", Unit(200)),
+            ("مائتين", Unit(200)),
+            ("مئتين", Unit(200)),
+            ("ألف", Scale(1_000)),
+            ("آلاف", Scale(1_000)),
+            ("مليون", Scale(1_000_000)),
+            ("ملايين", Scale(1_000_000)),
+            ("مليار", Scale(1_000_000_000)),
+            ("و", Connector),
+        ],
+        #[cfg(feature = "bengali")]
+        Language::Ben => &[
+            ("শূন্য", Unit(0)),
+            ("এক", Unit(1)),
+            ("দুই", Unit(2)),
+            ("দু", Unit(2)),
+            ("তিন", Unit(3)),
+            ("চার", Unit(4)),
+            ("পাঁচ", Unit(5)),
+            ("ছয়", Unit(6)),
+            ("সাত", Unit(7)),
+            ("আট", Unit(8)),
+            ("নয়", Unit(9)),
+            ("দশ", Unit(10)),
+            ("এগারো", Unit(11)),
+            ("বারো", Unit(12)),
+            ("তেরো", Unit(13)),
+            ("চোদ্দ", Unit(14)),
+            ("পনেরো", Unit(15)),
+            ("ষোলো", Unit(16)),
+            ("সতেরো", Unit(17)),
+            ("আঠারো", Unit(18)),
+            ("উনিশ", Unit(19)),
+            ("বিশ", Unit(20)),
+            ("ত্রিশ", Unit(30)),
+            ("চল্লিশ", Unit(40)),
+            ("পঞ্চাশ", Unit(50)),
+            ("ষাট", Unit(60)),
+            ("সত্তর", Unit(70)),
+            ("আশি", Unit(80)),
+            ("নব্বই", Unit(90)),
+            ("শত", Multiplier(100)),
+            ("শো", Multiplier(100)),
+            ("শ", Multiplier(100)),
+            ("হাজার", Scale(1_000)),
+            ("লাখ", Scale(100_000)),
+            ("কোটি", Scale(10_000_000)),
+        ],
+        #[cfg(feature = "mandarin")]
+        Language::Cmn => &[
+            ("零", Unit(0)),
+            ("〇", Unit(0)),
+            ("一", Unit(1)),
+            ("二", Unit(2)),
+            ("两", Unit(2)),
+            ("三", Unit(3)),
+            ("四", Unit(4)),
+            ("五", Unit(5)),
+            ("六", Unit(6)),
+            ("七", Unit(7)),
+            ("八", Unit(8)),
+            ("九", Unit(9)),
+            ("十", Multiplier(10)),
+            ("百", Multiplier(100)),
+            ("千", Multiplier(1_000)),
+            ("万", Scale(10_000)),
+            ("萬", Scale(10_000)),
+            ("亿", Scale(100_000_000)),
+            ("億", Scale(100_000_000)),
+        ],
+        #[cfg(feature = "german")]
+        Language::Deu => &[
+            ("null", Unit(0)),
+            ("eins", Unit(1)),
+            ("ein", Unit(1)),
+            ("eine", Unit(1)),
+            ("zwei", Unit(2)),
+            ("zwo", Unit(2)),
+            ("drei", Unit(3)),
+            ("vier", Unit(4)),
+            ("fünf", Unit(5)),
+            ("sechs", Unit(6)),
+            ("sieben", Unit(7)),
+            ("acht", Unit(8)),
+            ("neun", Unit(9)),
+            ("zehn", Unit(10)),
+            ("elf", Unit(11)),
+            ("zwölf", Unit(12)),
+            ("dreizehn", Unit(13)),
+            ("vierzehn", Unit(14)),
+            ("fünfzehn", Unit(15)),
+            ("sechzehn", Unit(16)),
+            ("siebzehn", Unit(17)),
+            ("achtzehn", Unit(18)),
+            ("neunzehn", Unit(19)),
+            ("zwanzig", Unit(20)),
+            ("dreißig", Unit(30)),
+            ("vierzig", Unit(40)),
+            ("fünfzig", Unit(50)),
+            ("sechzig", Unit(60)),
+            ("siebzig", Unit(70)),
+            ("achtzig", Unit(80)),
+            ("neunzig", Unit(90)),
+            ("hundert", Multiplier(100)),
+            ("tausend", Scale(1_000)),
+            ("million", Scale(1_000_000)),
+            ("millionen", Scale(1_000_000)),
+            ("milliarde", Scale(1_000_000_000)),
+            ("milliarden", Scale(1_000_000_000)),
+            ("und", Connector),
+        ],
+        #[cfg(feature = "english")]
+        Language::Eng => &[
+            ("zero", Unit(0)),
+            ("one", Unit(1)),
+            ("two", Unit(2)),
+            ("three", Unit(3)),
+            ("four", Unit(4)),
+            ("five", Unit(5)),
+            ("six", Unit(6)),
+            ("seven", Unit(7)),
+            ("eight", Unit(8)),
+            ("nine", Unit(9)),
+            ("ten", Unit(10)),
+            ("eleven", Unit(11)),
+            ("twelve", Unit(12)),
+            ("thirteen", Unit(13)),
+            ("fourteen", Unit(14)),
+            ("fifteen", Unit(15)),
+            ("sixteen", Unit(16)),
+            ("seventeen", Unit(17)),
+            ("eighteen", Unit(18)),
+            ("nineteen", Unit(19)),
+            ("twenty", Unit(20)),
+            ("thirty", Unit(30)),
+            ("forty", Unit(40)),
+            ("fifty", Unit(50)),
+            ("sixty", Unit(60)),
+            ("seventy", Unit(70)),
+            ("eighty", Unit(80)),
+            ("ninety", Unit(90)),
+            ("hundred", Multiplier(100)),
+            ("thousand", Scale(1_000)),
+            ("million", Scale(1_000_000)),
+            ("billion", Scale(1_000_000_000)),
+            ("trillion", Scale(1_000_000_000_000)),
+            ("and", Connector),
+        ],
+        #[cfg(feature = "esperanto")]
+        Language::Epo => &[
+            ("nul", Unit(0)),
+            ("unu", Unit(1)),
+            ("du", Unit(2)),
+            ("tri", Unit(3)),
+            ("kvar", Unit(4)),
+            ("kvin", Unit(5)),
+            ("ses", Unit(6)),
+            ("sep", Unit(7)),
+            ("ok", Unit(8)),
+            ("naŭ", Unit(9)),
+            ("dek", Multiplier(10)),
+            ("cent", Multiplier(100)),
+            ("mil", Scale(1_000)),
+            ("miliono", Scale(1_000_000)),
+            ("milionoj", Scale(1_000_000)),
+            ("miliardo", Scale(1_000_000_000)),
+            ("miliardoj", Scale(1_000_000_000)),
+        ],
+        #[cfg(feature = "french")]
+        Language::Fra => &[
+            ("zéro", Unit(0)),
+            ("un", Unit(1)),
+            ("une", Unit(1)),
+            ("deux", Unit(2)),
+            ("trois", Unit(3)),
+            ("quatre", Unit(4)),
+            ("cinq", Unit(5)),
+            ("six", Unit(6)),
+            ("sept", Unit(7)),
+            ("huit", Unit(8)),
+            ("neuf", Unit(9)),
+            ("dix", Unit(10)),
+            ("onze", Unit(11)),
+            ("douze", Unit(12)),
+            ("treize", Unit(13)),
+            ("quatorze", Unit(14)),
+            ("quinze", Unit(15)),
+            ("seize", Unit(16)),
+            // NOTE: "vingt" multiplies in "quatre-vingts"
+            ("vingt", Multiplier(20)),
+            ("vingts", Multiplier(20)),
+            ("trente", Unit(30)),
+            ("quarante", Unit(40)),
+            ("cinquante", Unit(50)),
+            ("soixante", Unit(60)),
+            ("septante", Unit(70)),
+            ("huitante", Unit(80)),
+            ("nonante", Unit(90)),
+            ("cent", Multiplier(100)),
+            ("cents", Multiplier(100)),
+            ("mille", Scale(1_000)),
+            ("million", Scale(1_000_000)),
+            ("millions", Scale(1_000_000)),
+            ("milliard", Scale(1_000_000_000)),
+            ("milliards", Scale(1_000_000_000)),
+            ("et", Connector),
+        ],
+        #[cfg(feature = "hindi")]
+        Language::Hin => &[
+            ("शून्य", Unit(0)),
+            ("एक", Unit(1)),
+            ("दो", Unit(2)),
+            ("तीन", Unit(3)),
+            ("चार", Unit(4)),
+            ("पाँच", Unit(5)),
+            ("पांच", Unit(5)),
+            ("छह", Unit(6)),
+            ("छः", Unit(6)),
+            ("सात", Unit(7)),
+            ("आठ", Unit(8)),
+            ("नौ", Unit(9)),
+            ("दस", Unit(10)),
+            ("ग्यारह", Unit(11)),
+            ("बारह", Unit(12)),
+            ("तेरह", Unit(13)),
+            ("चौदह", Unit(14)),
+            ("पंद्रह", Unit(15)),
+            ("पन्द्रह", Unit(15)),
+            ("सोलह", Unit(16)),
+            ("सत्रह", Unit(17)),
+            ("अठारह", Unit(18)),
+            ("उन्नीस", Unit(19)),
+            ("बीस", Unit(20)),
+            ("इक्कीस", Unit(21)),
+            ("बाईस", Unit(22)),
+            ("तेईस", Unit(23)),
+            ("चौबीस", Unit(24)),
+            ("पच्चीस", Unit(25)),
+            ("छब्बीस", Unit(26)),
+            ("सत्ताईस", Unit(27)),
+            ("अट्ठाईस", Unit(28)),
+            ("उनतीस", Unit(29)),
+            ("तीस", Unit(30)),
+            ("चालीस", Unit(40)),
+            ("पचास", Unit(50)),
+            ("साठ", Unit(60)),
+            ("सत्तर", Unit(70)),
+            ("अस्सी", Unit(80)),
+            ("नब्बे", Unit(90)),
+            ("सौ", Multiplier(100)),
+            ("हज़ार", Scale(1_000)),
+            ("हजार", Scale(1_000)),
+            ("लाख", Scale(100_000)),
+            ("करोड़", Scale(10_000_000)),
+            ("करोड", Scale(10_000_000)),
+        ],
+        #[cfg(feature = "indonesian")]
+        Language::Ind => &[
+            ("nol", Unit(0)),
+            ("satu", Unit(1)),
+            ("dua", Unit(2)),
+            ("tiga", Unit(3)),
+            ("empat", Unit(4)),
+            ("lima", Unit(5)),
+            ("enam", Unit(6)),
+            ("tujuh", Unit(7)),
+            ("delapan", Unit(8)),
+            ("sembilan", Unit(9)),
+            ("sepuluh", Unit(10)),
+            ("sebelas", Unit(11)),
+            ("belas", Teen),
+            ("puluh", Multiplier(10)),
+            ("seratus", Unit(100)),
+            ("ratus", Multiplier(100)),
+            ("seribu", Scale(1_000)),
+            ("ribu", Scale(1_000)),
+            ("juta", Scale(1_000_000)),
+            ("miliar", Scale(1_000_000_000)),
+        ],
+        #[cfg(feature = "italian")]
+        Language::Ita => &[
+            ("zero", Unit(0)),
+            ("uno", Unit(1)),
+            ("una", Unit(1)),
+            ("un", Unit(1)),
+            ("due", Unit(2)),
+            ("tre", Unit(3)),
+            ("tré", Unit(3)),
+            ("quattro", Unit(4)),
+            ("cinque", Unit(5)),
+            ("sei", Unit(6)),
+            ("sette", Unit(7)),
+            ("otto", Unit(8)),
+            ("nove", Unit(9)),
+            ("dieci", Unit(10)),
+            ("undici", Unit(11)),
+            ("dodici", Unit(12)),
+            ("tredici", Unit(13)),
+            ("quattordici", Unit(14)),
+            ("quindici", Unit(15)),
+            ("sedici", Unit(16)),
+            ("diciassette", Unit(17)),
+            ("diciotto", Unit(18)),
+            ("diciannove", Unit(19)),
+            // NOTE: tens drop their final vowel before "uno" and "otto", as in "ventuno"
+            ("venti", Unit(20)),
+            ("vent", Unit(20)),
+            ("trenta", Unit(30)),
+            ("trent", Unit(30)),
+            ("quaranta", Unit(40)),
+            ("quarant", Unit(40)),
+            ("cinquanta", Unit(50)),
+            ("cinquant", Unit(50)),
+            ("sessanta", Unit(60)),
+            ("sessant", Unit(60)),
+            ("settanta", Unit(70)),
+            ("settant", Unit(70)),
+            ("ottanta", Unit(80)),
+            ("ottant", Unit(80)),
+            ("novanta", Unit(90)),
+            ("novant", Unit(90)),
+            ("cento", Multiplier(100)),
+            ("mille", Scale(1_000)),
+            ("mila", Scale(1_000)),
+            ("milione", Scale(1_000_000)),
+            ("milioni", Scale(1_000_000)),
+            ("miliardo", Scale(1_000_000_000)),
+            ("miliardi", Scale(1_000_000_000)),
+        ],
+        #[cfg(feature = "japanese")]
+        Language::Jpn => &[
+            ("零", Unit(0)),
+            ("〇", Unit(0)),
+            ("一", Unit(1)),
+            ("二", Unit(2)),
+            ("三", Unit(3)),
+            ("四", Unit(4)),
+            ("五", Unit(5)),
+            ("六", Unit(6)),
+            ("七", Unit(7)),
+            ("八", Unit(8)),
+            ("九", Unit(9)),
+            ("十", Multiplier(10)),
+            ("百", Multiplier(100)),
+            ("千", Multiplier(1_000)),
+            ("万", Scale(10_000)),
+            ("億", Scale(100_000_000)),
+            ("兆", Scale(1_000_000_000_000)),
+        ],
+        #[cfg(feature = "portuguese")]
+        Language::Por => &[
+            ("zero", Unit(0)),
+            ("um", Unit(1)),
+            ("uma", Unit(1)),
+            ("dois", Unit(2)),
+            ("duas", Unit(2)),
+            ("três", Unit(3)),
+            ("quatro", Unit(4)),
+            ("cinco", Unit(5)),
+            ("seis", Unit(6)),
+            ("sete", Unit(7)),
+            ("oito", Unit(8)),
+            ("nove", Unit(9)),
+            ("dez", Unit(10)),
+            ("onze", Unit(11)),
+            ("doze", Unit(12)),
+            ("treze", Unit(13)),
+            ("catorze", Unit(14)),
+            ("quatorze", Unit(14)),
+            ("quinze", Unit(15)),
+            ("dezesseis", Unit(16)),
+            ("dezasseis", Unit(16)),
+            ("dezessete", Unit(17)),
+            ("dezassete", Unit(17)),
+            ("dezoito", Unit(18)),
+            ("dezenove", Unit(19)),
+            ("dezanove", Unit(19)),
+            ("vinte", Unit(20)),
+            ("trinta", Unit(30)),
+            ("quarenta", Unit(40)),
+            ("cinquenta", Unit(50)),
+            ("sessenta", Unit(60)),
+            ("setenta", Unit(70)),
+            ("oitenta", Unit(80)),
+            ("noventa", Unit(90)),
+            ("cem", Unit(100)),
+            ("cento", Unit(100)),
+            ("duzentos", Unit(200)),
+            ("duzentas", Unit(200)),
+            ("trezentos", Unit(300)),
+            ("trezentas", Unit(300)),
+            ("quatrocentos", Unit(400)),
+            ("quatrocentas", Unit(400)),
+            ("quinhentos", Unit(500)),
+            ("quinhentas", Unit(500)),
+            ("seiscentos", Unit(600)),
+            ("seiscentas", Unit(600)),
+            ("setecentos", Unit(700)),
+            ("setecentas", Unit(700)),
+            ("oitocentos", Unit(800)),
+            ("oitocentas", Unit(800)),
+            ("novecentos", Unit(900)),
+            ("novecentas", Unit(900)),
+            ("mil", Scale(1_000)),
+            ("milhão", Scale(1_000_000)),
+            ("milhões", Scale(1_000_000)),
+            ("bilhão", Scale(1_000_000_000)),
+            ("bilhões", Scale(1_000_000_000)),
+            ("e", Connector),
+        ],
+        #[cfg(feature = "russian")]
+        Language::Rus => &[
+            ("ноль", Unit(0)),
+            ("один", Unit(1)),
+            ("одна", Unit(1)),
+            ("одно", Unit(1)),
+            ("два", Unit(2)),
+            ("две", Unit(2)),
+            ("три", Unit(3)),
+            ("четыре", Unit(4)),
+            ("пять", Unit(5)),
+            ("шесть", Unit(6)),
+            ("семь", Unit(7)),
+            ("восемь", Unit(8)),
+            ("девять", Unit(9)),
+            ("десять", Unit(10)),
+            ("одиннадцать", Unit(11)),
+            ("двенадцать", Unit(12)),
+            ("тринадцать", Unit(13)),
+            ("четырнадцать", Unit(14)),
+            ("пятнадцать", Unit(15)),
+            ("шестнадцать", Unit(16)),
+            ("семнадцать", Unit(17)),
+            ("восемнадцать", Unit(18)),
+            ("девятнадцать", Unit(19)),
+            ("двадцать", Unit(20)),
+            ("тридцать", Unit(30)),
+            ("сорок", Unit(40)),
+            ("пятьдесят", Unit(50)),
+            ("шестьдесят", Unit(60)),
+            ("семьдесят", Unit(70)),
+            ("восемьдесят", Unit(80)),
+            ("девяносто", Unit(90)),
+            ("сто", Unit(100)),
+            ("двести", Unit(200)),
+            ("триста", Unit(300)),
+            ("четыреста", Unit(400)),
+            ("пятьсот", Unit(500)),
+            ("шестьсот", Unit(600)),
+            ("семьсот", Unit(700)),
+            ("восемьсот", Unit(800)),
+            ("девятьсот", Unit(900)),
+            ("тысяча", Scale(1_000)),
+            ("тысячи", Scale(1_000)),
+            ("тысяч", Scale(1_000)),
+            ("миллион", Scale(1_000_000)),
+            ("миллиона", Scale(1_000_000)),
+            ("миллионов", Scale(1_000_000)),
+            ("миллиард", Scale(1_000_000_000)),
+            ("миллиарда", Scale(1_000_000_000)),
+            ("миллиардов", Scale(1_000_000_000)),
+        ],
+        #[cfg(feature = "spanish")]
+        Language::Spa => &[
+            ("cero", Unit(0)),
+            ("uno", Unit(1)),
+            ("una", Unit(1)),
+            ("un", Unit(1)),
+            ("dos", Unit(2)),
+            ("tres", Unit(3)),
+            ("cuatro", Unit(4)),
+            ("cinco", Unit(5)),
+            ("seis", Unit(6)),
+            ("siete", Unit(7)),
+            ("ocho", Unit(8)),
+            ("nueve", Unit(9)),
+            ("diez", Unit(10)),
+            ("once", Unit(11)),
+            ("doce", Unit(12)),
+            ("trece", Unit(13)),
+            ("catorce", Unit(14)),
+            ("quince", Unit(15)),
+            ("dieciséis", Unit(16)),
+            ("diecisiete", Unit(17)),
+            ("dieciocho", Unit(18)),
+            ("diecinueve", Unit(19)),
+            ("veinte", Unit(20)),
+            ("veintiuno", Unit(21)),
+            ("veintiún", Unit(21)),
+            ("veintiuna", Unit(21)),
+            ("veintidós", Unit(22)),
+            ("veintitrés", Unit(23)),
+            ("veinticuatro", Unit(24)),
+            ("veinticinco", Unit(25)),
+            ("veintiséis", Unit(26)),
+            ("veintisiete", Unit(27)),
+            ("veintiocho", Unit(28)),
+            ("veintinueve", Unit(29)),
+            ("treinta", Unit(30)),
+            ("cuarenta", Unit(40)),
+            ("cincuenta", Unit(50)),
+            ("sesenta", Unit(60)),
+            ("setenta", Unit(70)),
+            ("ochenta", Unit(80)),
+            ("noventa", Unit(90)),
+            ("cien", Unit(100)),
+            ("ciento", Unit(100)),
+            ("doscientos", Unit(200)),
+            ("doscientas", Unit(200)),
+            ("trescientos", Unit(300)),
+            ("trescientas", Unit(300)),
+            ("cuatrocientos", Unit(400)),
+            ("cuatrocientas", Unit(400)),
+            ("quinientos", Unit(500)),
+            ("quinientas", Unit(500)),
+            ("seiscientos", Unit(600)),
+            ("seiscientas", Unit(600)),
+            ("setecientos", Unit(700)),
+            ("setecientas", Unit(700)),
+            ("ochocientos", Unit(800)),
+            ("ochocientas", Unit(800)),
+            ("novecientos", Unit(900)),
+            ("novecientas", Unit(900)),
+            ("mil", Scale(1_000)),
+            ("millón", Scale(1_000_000)),
+            ("millones", Scale(1_000_000)),
+            ("y", Connector),
+        ],
+        #[cfg(feature = "turkish")]
+        Language::Tur => &[
+            ("sıfır", Unit(0)),
+            ("bir", Unit(1)),
+            ("iki", Unit(2)),
+            ("üç", Unit(3)),
+            ("dört", Unit(4)),
+            ("beş", Unit(5)),
+            ("altı", Unit(6)),
+            ("yedi", Unit(7)),
+            ("sekiz", Unit(8)),
+            ("dokuz", Unit(9)),
+            ("on", Unit(10)),
+            ("yirmi", Unit(20)),
+            ("otuz", Unit(30)),
+            ("kırk", Unit(40)),
+            ("elli", Unit(50)),
+            ("altmış", Unit(60)),
+            ("yetmiş", Unit(70)),
+            ("seksen", Unit(80)),
+            ("doksan", Unit(90)),
+            ("yüz", Multiplier(100)),
+            ("bin", Scale(1_000)),
+            ("milyon", Scale(1_000_000)),
+            ("milyar", Scale(1_000_000_000)),
+        ],
+        #[cfg(feature = "urdu")]
+        Language::Urd => &[
+            ("صفر", Unit(0)),
+            ("ایک", Unit(1)),
+            ("دو", Unit(2)),
+            ("تین", Unit(3)),
+            ("چار", Unit(4)),
+            ("پانچ", Unit(5)),
+            ("چھ", Unit(6)),
+            ("چھے", Unit(6)),
+            ("سات", Unit(7)),
+            ("آٹھ", Unit(8)),
+            ("نو", Unit(9)),
+            ("دس", Unit(10)),
+            ("گیارہ", Unit(11)),
+            ("بارہ", Unit(12)),
+            ("تیرہ", Unit(13)),
+            ("چودہ", Unit(14)),
+            ("پندرہ", Unit(15)),
+            ("سولہ", Unit(16)),
+            ("سترہ", Unit(17)),
+            ("اٹھارہ", Unit(18)),
+            ("انیس", Unit(19)),
+            ("بیس", Unit(20)),
+            ("تیس", Unit(30)),
+            ("چالیس", Unit(40)),
+            ("پچاس", Unit(50)),
+            ("ساٹھ", Unit(60)),
+            ("ستر", Unit(70)),
+            ("اسی", Unit(80)),
+            ("نوے", Unit(90)),
+            ("سو", Multiplier(100)),
+            ("ہزار", Scale(1_000)),
+            ("لاکھ", Scale(100_000)),
+            ("کروڑ", Scale(10_000_000)),
+        ],
+        #[allow(unreachable_patterns)]
+        _ => &[],
+    }
+}
+
+/// Counters of `lang`, which follow numbers in words of the lexicon, as "人" in "三百人".
+const fn counters(lang: Language) -> &'static [char] {
+    match lang {
+        #[cfg(feature = "mandarin")]
+        Language::Cmn => &[
+            '个', '人', '位', '名', '只', '本', '条', '张', '件', '次', '年', '月', '日', '天',
+            '岁', '元', '块', '米', '号', '倍', '层', '台', '辆',
+        ],
+        #[cfg(feature = "japanese")]
+        Language::Jpn => &[
+            '人', '個', '本', '枚', '回', '年', '月', '日', '時', '円', '歳', '匹', '台', '冊',
+            '階', '番', '件', '名',
+        ],
+        #[allow(unreachable_patterns)]
+        _ => &[],
+    }
+}
+
+/// Whether `word` is a number followed by a counter of `lang`, as "三百人".
+fn is_counted(lang: Language, word: &str) -> bool {
+    word.strip_suffix(counters(lang))
+        .is_some_and(|number| !number.is_empty() && pieces(lang, number).is_some())
+}
+
+/// A number spelled out in words, such as "twenty-three" or "dreiundzwanzig".
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct NumberWords<'text> {
+    /// The words, including the spaces, hyphens and connectors between them.
+    pub text: &'text str,
+    pub span: Span,
+    pub value: u64,
+}
+
+/// The number read so far from number words.
+#[derive(Clone, Copy, Debug, Default)]
+struct Reading {
+    /// The sum of the parts already multiplied by a scale.
+    total: u64,
+    /// The part since the last scale.
+    group: u64,
+    /// The last scale, which following scales have to be smaller than.
+    scale: Option<u64>,
+    /// Whether a connector came last.
+    joined: bool,
+}
+
+impl Reading {
+    /// Read the next number word, unless it can't follow the words read so far,
+    /// as "two" after "one".
+    fn read(&mut self, value: Value) -> bool {
+        match value {
+            // NOTE: "two and thousand" is not a number
+            Value::Teen | Value::Multiplier(_) | Value::Scale(_) if self.joined => return false,
+            Value::Unit(unit) => {
+                // NOTE: the smallest power of ten above the unit, as 10 for "three" and 100 for "twenty"
+                let place = 10_u64.saturating_pow(unit.checked_ilog10().map_or(1, |log| log + 1));
+                // NOTE: units may come before tens when joined, as in "dreiundzwanzig"
+                let inverted = self.joined && self.group % 100 < 10 && unit >= 20 && unit % 10 == 0;
+                // NOTE: teens may follow sixty and eighty, as in French "soixante-dix"
+                let vigesimal = (10..20).contains(&unit) && matches!(self.group % 100, 60 | 80);
+                if !self.group.is_multiple_of(place) && !inverted && !vigesimal {
+                    return false;
+                }
+                self.group = self.group.saturating_add(unit);
+            }
+            Value::Teen => {
+                if self.group % 100 >= 10 {
+                    return false;
+                }
+                self.group += 10;
+            }
+            Value::Multiplier(multiplier) => {
+                let low = self.group % multiplier;
+                self.group =
+                    (self.group - low).saturating_add(low.max(1).saturating_mul(multiplier));
+            }
+            Value::Scale(scale) => {
+                if self.scale.is_some_and(|last| scale >= last) {
+                    return false;
+                }
+                self.total = self
+                    .total
+                    .saturating_add(self.group.max(1).saturating_mul(scale));
+                self.group = 0;
+                self.scale = Some(scale);
+            }
+            Value::Connector => {
+                self.joined = true;
+                return true;
+            }
+        }
+        self.joined = false;
+        true
+    }
+
+    #[inline]
+    const fn value(&self) -> u64 {
+        self.total.saturating_add(self.group)
+    }
+}
+
+/// Split `word` into the fewest number words of `lang` that make it up entirely, if any.
+fn pieces(lang: Language, word: &str) -> Option<Vec<Value>> {
+    let word = word.to_lowercase();
+    // NOTE: the fewest pieces making up the first `i` bytes, with where and what the last one is
+    let mut best: Vec<Option<(usize, usize, Value)>> = vec![None; word.len() + 1];
+    best[0] = Some((0, 0, Value::Connector));
+    for start in 0..word.len() {
+        let Some((count, _, _)) = best[start] else {
+            continue;
+        };
+        for &(piece, value) in words(lang) {
+            if word[start..].starts_with(piece) {
+                let end = start + piece.len();
+                if best[end].is_none_or(|(other, _, _)| count + 1 < other) {
+                    best[end] = Some((count + 1, start, value));
+                }
+            }
+        }
+    }
+
+    let mut values = Vec::new();
+    let mut end = word.len();
+    while end > 0 {
+        let (_, start, value) = best[end]?;
+        values.push(value);
+        end = start;
+    }
+    values.reverse();
+    (!values.is_empty()).then_some(values)
+}
+
+/// Whether `token` may stand between the words of a number.
+#[inline]
+fn is_separator(token: &Token) -> bool {
+    matches!(
+        token,
+        Token::Separator(_) | Token::Whitespace(_) | Token::SeparatorOrWhitespace(_)
+    ) || matches!(token.as_ref(), "-" | "‐")
+}
+
+/// Byte ranges of the numbers spelled out in `text`, along with their values.
+pub(crate) fn find(text: &str, lang: Language) -> Vec<(Range<usize>, u64)> {
+    let finish = |(range, reading): (Range<usize>, Reading)| (range, reading.value());
+    let mut found = Vec::new();
+    let mut current: Option<(Range<usize>, Reading)> = None;
+    let mut joined = false;
+
+    // NOTE: not `Tokenizer::new`, as calls taking a `Language` are unreachable without languages
+    let words = Tokenizer { lang }
+        .token_positions(text)
+        .flat_map(|(position, token)| {
+            let start = position.into_inner();
+            let word = &text[start..start + token.as_ref().len()];
+            // NOTE: Chinese and Japanese numerals are split off unknown words, as in "二十三个",
+            // and off the words of the lexicon counting something, as in "三百人"
+            let chars = crate::segmentation::is_unknown(lang, word) || is_counted(lang, word);
+            let parts: Vec<_> = if chars {
+                word.char_indices()
+                    .map(|(index, c)| {
+                        (
+                            start + index,
+                            Token::from(&word[index..index + c.len_utf8()]),
+                        )
+                    })
+                    .collect()
+            } else {
+                vec![(start, token)]
+            };
+            parts
+        });
+    for (start, token) in words {
+        let range = start..start + token.as_ref().len();
+        match pieces(lang, token.as_ref()) {
+            Some(values) if values.iter().all(|&value| value == Value::Connector) => {
+                joined = current.is_some();
+            }
+            Some(values) => {
+                let continued = current.clone().and_then(|(span, mut reading)| {
+                    let read = (!joined || reading.read(Value::Connector))
+                        && values.iter().all(|&value| reading.read(value));
+                    read.then_some((span.start..range.end, reading))
+                });
+                if continued.is_none() {
+                    found.extend(current.take().map(finish));
+                }
+                current = continued.or_else(|| {
+                    let mut reading = Reading::default();
+                    let read = values.iter().all(|&value| reading.read(value));
+                    read.then_some((range, reading))
+                });
+                joined = false;
+            }
+            None if current.is_some() && is_separator(&token) => {}
+            None => {
+                found.extend(current.take().map(finish));
+                joined = false;
+            }
+        }
+    }
+    found.extend(current.map(finish));
+    found
+}
+
+/// Find the numbers spelled out in `text`, written in `lang`, along with their values.
+///
+/// Number words may be separated by spaces, hyphens and connectors such as "and",
+/// or be written together as in "dreiundzwanzig" or "二十三".
+/// Words that can't follow each other start a new number,
+/// so "one two three" are three numbers.
+pub fn number_words(text: &str, lang: Language) -> impl Iterator<Item = NumberWords<'_>> {
+    let mut spanner = Spanner::new(text);
+    find(text, lang)
+        .into_iter()
+        .map(move |(range, value)| NumberWords {
+            text: &text[range.clone()],
+            span: spanner.span(range),
+            value,
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+
+    fn values(lang: Language, text: &str) -> Vec<(&str, u64)> {
+        number_words(text, lang)
+            .map(|number| (number.text, number.value))
+            .collect()
+    }
+
+    #[cfg(feature = "english")]
+    #[test]
+    fn english() {
+        assert_eq!(
+            values(
+                Language::Eng,
+                "Twenty-three cats, one hundred and five dogs and two thousand and one birds."
+            ),
+            [
+                ("Twenty-three", 23),
+                ("one hundred and five", 105),
+                ("two thousand and one", 2001)
+            ]
+        );
+        assert_eq!(
+            values(
+                Language::Eng,
+                "one two, three million four hundred thousand"
+            ),
+            [
+                ("one", 1),
+                ("two", 2),
+                ("three million four hundred thousand", 3_400_000)
+            ]
+        );
+        assert_eq!(values(Language::Eng, "often, tent and none"), []);
+
+        let number = number_words("I saw eleven", Language::Eng).next().unwrap();
+        assert_eq!(number.span.bytes(), 6..12);
+    }
+
+    #[cfg(all(feature = "french", feature = "german", feature = "russian"))]
+    #[test]
+    fn european() {
+        assert_eq!(
+            values(
+                Language::Fra,
+                "vingt-trois, quatre-vingt-dix-sept et trois cent vingt et un"
+            ),
+            [
+                ("vingt-trois", 23),
+                ("quatre-vingt-dix-sept", 97),
+                ("trois cent vingt et un", 321)
+            ]
+        );
+        assert_eq!(
+            values(Language::Deu, "dreiundzwanzig und zweitausendvierhundert"),
+            [("dreiundzwanzig", 23), ("zweitausendvierhundert", 2400)]
+        );
+        assert_eq!(
+            values(
+                Language::Deu,
+                "hundertdreiundzwanzig, zweitausenddreihundertvierundfünfzig und neunzehnhundertneunundneunzig"
+            ),
+            [
+                ("hundertdreiundzwanzig", 123),
+                ("zweitausenddreihundertvierundfünfzig", 2354),
+                ("neunzehnhundertneunundneunzig", 1999)
+            ]
+        );
+        assert_eq!(
+            values(Language::Rus, "двадцать три и сто одна тысяча"),
+            [("двадцать три", 23), ("сто одна тысяча", 101_000)]
+        );
+    }
+
+    #[cfg(all(feature = "spanish", feature = "italian", feature = "indonesian"))]
+    #[test]
+    fn compounds() {
+        assert_eq!(
+            values(
+                Language::Spa,
+                "veintitrés y mil novecientos noventa y nueve"
+            ),
+            [
+                ("veintitrés", 23),
+                ("mil novecientos noventa y nueve", 1999)
+            ]
+        );
+        assert_eq!(
+            values(Language::Ita, "duemilaventuno"),
+            [("duemilaventuno", 2021)]
+        );
+        assert_eq!(
+            values(Language::Ind, "dua belas dan seratus dua puluh tiga"),
+            [("dua belas", 12), ("seratus dua puluh tiga", 123)]
+        );
+    }
+
+    #[cfg(all(feature = "mandarin", feature = "japanese", feature = "arabic"))]
+    #[test]
+    fn scripts() {
+        assert_eq!(values(Language::Cmn, "二十三个学生"), [("二十三", 23)]);
+        assert_eq!(
+            values(Language::Cmn, "一万两千零五"),
+            [("一万两千零五", 12_005)]
+        );
+        assert_eq!(values(Language::Cmn, "一样"), []);
+        assert_eq!(values(Language::Jpn, "三百人"), [("三百", 300)]);
+        assert_eq!(values(Language::Cmn, "三个人"), [("三", 3)]);
+        assert_eq!(values(Language::Ara, "ثلاثة وعشرون"), [("ثلاثة وعشرون", 23)]);
+        assert_eq!(
+            values(Language::Ara, "مائة وثلاثة وعشرون"),
+            [("مائة وثلاثة وعشرون", 123)]
+        );
+    }
+}
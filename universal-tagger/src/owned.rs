@@ -0,0 +1,341 @@
+use itertools::Position;
+
+use crate::{Number, NumberKind, Span, Tag, Token, TokenInfo};
+
+/// An owned [`Token`], which can outlive the text it was found in.
+///
+/// This mirrors [`Token`] variant by variant, and both serialize the same way,
+/// so that tokens written borrowed can be read back owned.
+/// Borrowed tokens can also be read back without copying from binary formats,
+/// but not from JSON with escaped strings.
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum TokenBuf {
+    Separator(String),
+    Whitespace(String),
+    SeparatorOrWhitespace(String),
+    Letter(String),
+    Punctuation(String),
+    Number(NumberBuf),
+    Symbol(String),
+    Mark(String),
+    LetterOrPunctuation(String),
+    NumberOrPunctuation(String),
+    LetterOrNumber(String),
+    LetterOrMark(String),
+    NumberOrMark(String),
+    Other(String),
+    LetterOrOther(String),
+    NumberOrOther(String),
+    OtherOrPunctuation(String),
+    Mixed(String),
+    Url(String),
+    Email(String),
+    Mention(String),
+    Hashtag(String),
+    IpAddress(String),
+    Path(String),
+    Emoji(String),
+}
+
+/// An owned [`Number`].
+#[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct NumberBuf {
+    pub text: String,
+    pub value: Option<f64>,
+    pub kind: NumberKind,
+}
+
+impl PartialEq for NumberBuf {
+    #[inline]
+    fn eq(&self, other: &Self) -> bool {
+        self.as_number() == other.as_number()
+    }
+}
+
+impl Eq for NumberBuf {}
+
+impl NumberBuf {
+    /// Borrow this as a [`Number`].
+    #[inline]
+    #[must_use]
+    pub fn as_number(&self) -> Number<'_> {
+        Number {
+            text: &self.text,
+            value: self.value,
+            kind: self.kind,
+        }
+    }
+}
+
+impl From<Number<'_>> for NumberBuf {
+    #[inline]
+    fn from(number: Number<'_>) -> Self {
+        Self {
+            text: number.text.to_owned(),
+            value: number.value,
+            kind: number.kind,
+        }
+    }
+}
+
+impl From<Token<'_>> for TokenBuf {
+    fn from(token: Token<'_>) -> Self {
+        match token {
+            Token::Separator(word) => Self::Separator(word.to_owned()),
+            Token::Whitespace(word) => Self::Whitespace(word.to_owned()),
+            Token::SeparatorOrWhitespace(word) => Self::SeparatorOrWhitespace(word.to_owned()),
+            Token::Letter(word) => Self::Letter(word.to_owned()),
+            Token::Punctuation(word) => Self::Punctuation(word.to_owned()),
+            Token::Number(number) => Self::Number(number.into()),
+            Token::Symbol(word) => Self::Symbol(word.to_owned()),
+            Token::Mark(word) => Self::Mark(word.to_owned()),
+            Token::LetterOrPunctuation(word) => Self::LetterOrPunctuation(word.to_owned()),
+            Token::NumberOrPunctuation(word) => Self::NumberOrPunctuation(word.to_owned()),
+            Token::LetterOrNumber(word) => Self::LetterOrNumber(word.to_owned()),
+            Token::LetterOrMark(word) => Self::LetterOrMark(word.to_owned()),
+            Token::NumberOrMark(word) => Self::NumberOrMark(word.to_owned()),
+            Token::Other(word) => Self::Other(word.to_owned()),
+            Token::LetterOrOther(word) => Self::LetterOrOther(word.to_owned()),
+            Token::NumberOrOther(word) => Self::NumberOrOther(word.to_owned()),
+            Token::OtherOrPunctuation(word) => Self::OtherOrPunctuation(word.to_owned()),
+            Token::Mixed(word) => Self::Mixed(word.to_owned()),
+            Token::Url(word) => Self::Url(word.to_owned()),
+            Token::Email(word) => Self::Email(word.to_owned()),
+            Token::Mention(word) => Self::Mention(word.to_owned()),
+            Token::Hashtag(word) => Self::Hashtag(word.to_owned()),
+            Token::IpAddress(word) => Self::IpAddress(word.to_owned()),
+            Token::Path(word) => Self::Path(word.to_owned()),
+            Token::Emoji(word) => Self::Emoji(word.to_owned()),
+        }
+    }
+}
+
+impl TokenBuf {
+    /// Borrow this as a [`Token`].
+    #[must_use]
+    pub fn as_token(&self) -> Token<'_> {
+        match self {
+            Self::Separator(word) => Token::Separator(word),
+            Self::Whitespace(word) => Token::Whitespace(word),
+            Self::SeparatorOrWhitespace(word) => Token::SeparatorOrWhitespace(word),
+            Self::Letter(word) => Token::Letter(word),
+            Self::Punctuation(word) => Token::Punctuation(word),
+            Self::Number(number) => Token::Number(number.as_number()),
+            Self::Symbol(word) => Token::Symbol(word),
+            Self::Mark(word) => Token::Mark(word),
+            Self::LetterOrPunctuation(word) => Token::LetterOrPunctuation(word),
+            Self::NumberOrPunctuation(word) => Token::NumberOrPunctuation(word),
+            Self::LetterOrNumber(word) => Token::LetterOrNumber(word),
+            Self::LetterOrMark(word) => Token::LetterOrMark(word),
+            Self::NumberOrMark(word) => Token::NumberOrMark(word),
+            Self::Other(word) => Token::Other(word),
+            Self::LetterOrOther(word) => Token::LetterOrOther(word),
+            Self::NumberOrOther(word) => Token::NumberOrOther(word),
+            Self::OtherOrPunctuation(word) => Token::OtherOrPunctuation(word),
+            Self::Mixed(word) => Token::Mixed(word),
+            Self::Url(word) => Token::Url(word),
+            Self::Email(word) => Token::Email(word),
+            Self::Mention(word) => Token::Mention(word),
+            Self::Hashtag(word) => Token::Hashtag(word),
+            Self::IpAddress(word) => Token::IpAddress(word),
+            Self::Path(word) => Token::Path(word),
+            Self::Emoji(word) => Token::Emoji(word),
+        }
+    }
+}
+
+impl AsRef<str> for TokenBuf {
+    #[inline]
+    fn as_ref(&self) -> &str {
+        match self {
+            Self::Number(number) => &number.text,
+            Self::Separator(word)
+            | Self::Whitespace(word)
+            | Self::SeparatorOrWhitespace(word)
+            | Self::Letter(word)
+            | Self::Punctuation(word)
+            | Self::Symbol(word)
+            | Self::Mark(word)
+            | Self::LetterOrPunctuation(word)
+            | Self::NumberOrPunctuation(word)
+            | Self::LetterOrNumber(word)
+            | Self::LetterOrMark(word)
+            | Self::NumberOrMark(word)
+            | Self::Other(word)
+            | Self::LetterOrOther(word)
+            | Self::NumberOrOther(word)
+            | Self::OtherOrPunctuation(word)
+            | Self::Mixed(word)
+            | Self::Url(word)
+            | Self::Email(word)
+            | Self::Mention(word)
+            | Self::Hashtag(word)
+            | Self::IpAddress(word)
+            | Self::Path(word)
+            | Self::Emoji(word) => word,
+        }
+    }
+}
+
+impl Token<'_> {
+    /// Copy the text of this token, so that it outlives the text it was found in.
+    #[inline]
+    #[must_use]
+    pub fn into_owned(self) -> TokenBuf {
+        self.into()
+    }
+}
+
+/// An owned item of [`Tagger::tag`](crate::Tagger::tag), which can be sent across threads,
+/// cached or written to disk.
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct TaggedToken {
+    /// The byte offset of the token, and whether it starts or ends its sentence.
+    #[cfg_attr(feature = "serde", serde(with = "position"))]
+    pub position: Position<usize>,
+    pub token: TokenBuf,
+    pub tag: Option<Tag>,
+}
+
+// NOTE: `Position` doesn't implement `Eq`, though its equality is total for `usize`
+impl Eq for TaggedToken {}
+
+impl From<(Position<usize>, Token<'_>, Option<Tag>)> for TaggedToken {
+    #[inline]
+    fn from((position, token, tag): (Position<usize>, Token<'_>, Option<Tag>)) -> Self {
+        Self {
+            position,
+            token: token.into(),
+            tag,
+        }
+    }
+}
+
+/// An owned [`TokenInfo`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct TokenInfoBuf {
+    pub token: TokenBuf,
+    pub span: Span,
+}
+
+impl From<TokenInfo<'_>> for TokenInfoBuf {
+    #[inline]
+    fn from(info: TokenInfo<'_>) -> Self {
+        Self {
+            token: info.token.into(),
+            span: info.span,
+        }
+    }
+}
+
+/// (De)serialize an [`itertools::Position`] as an enum of the same shape.
+#[cfg(feature = "serde")]
+mod position {
+    use itertools::Position;
+    use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+    #[derive(Serialize, Deserialize)]
+    #[serde(remote = "Position<usize>")]
+    enum Remote {
+        First(usize),
+        Middle(usize),
+        Last(usize),
+        Only(usize),
+    }
+
+    #[inline]
+    pub(super) fn serialize<S: Serializer>(
+        position: &Position<usize>,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        Remote::serialize(position, serializer)
+    }
+
+    #[inline]
+    pub(super) fn deserialize<'de, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<Position<usize>, D::Error> {
+        Remote::deserialize(deserializer)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+
+    #[test]
+    fn own_tokens() {
+        let text = String::from("Call me at 10:30, Dr. Who! 👍🏽 1,000.5");
+        let tokens: Vec<_> = crate::token_positions(&text)
+            .map(|(_, token)| token.into_owned())
+            .collect();
+        let borrowed: Vec<_> = crate::token_positions(&text)
+            .map(|(_, token)| token)
+            .collect();
+        assert_eq!(
+            tokens.iter().map(TokenBuf::as_token).collect::<Vec<_>>(),
+            borrowed
+        );
+        drop(text);
+
+        let words: Vec<&str> = tokens.iter().map(AsRef::as_ref).collect();
+        assert_eq!(words.concat(), "Call me at 10:30, Dr. Who! 👍🏽 1,000.5");
+        std::thread::spawn(move || tokens.len()).join().unwrap();
+    }
+
+    #[cfg(all(feature = "serde", feature = "english"))]
+    #[test]
+    fn serialize() {
+        use crate::{Language, Tagger};
+
+        let text = "Dr. Smith paid 1,000.50 for the \"book\" 📚";
+        let tagged: Vec<TaggedToken> = Tagger::new(Language::Eng)
+            .tag(text)
+            .map(TaggedToken::from)
+            .collect();
+
+        let json = serde_json::to_string(&tagged).unwrap();
+        assert!(json.starts_with(
+            r#"[{"position":{"First":0},"token":{"Letter":"Dr"},"tag":"Abbreviation"},"#
+        ));
+        assert_eq!(
+            serde_json::from_str::<Vec<TaggedToken>>(&json).unwrap(),
+            tagged
+        );
+
+        let bytes = postcard::to_allocvec(&tagged).unwrap();
+        assert!(bytes.len() < json.len() / 3);
+        assert_eq!(
+            postcard::from_bytes::<Vec<TaggedToken>>(&bytes).unwrap(),
+            tagged
+        );
+
+        // NOTE: borrowed tokens are read without copying, and back as owned ones
+        let tokens: Vec<_> = crate::token_positions(text)
+            .map(|(_, token)| token)
+            .collect();
+        let bytes = postcard::to_allocvec(&tokens).unwrap();
+        assert_eq!(postcard::from_bytes::<Vec<Token>>(&bytes).unwrap(), tokens);
+        let owned: Vec<TokenBuf> = postcard::from_bytes(&bytes).unwrap();
+        assert_eq!(
+            owned,
+            tokens
+                .iter()
+                .map(|&token| token.into_owned())
+                .collect::<Vec<_>>()
+        );
+
+        let lang = serde_json::to_string(&Language::Eng).unwrap();
+        assert_eq!(lang, r#""Eng""#);
+        assert_eq!(
+            serde_json::from_str::<Language>(&lang).unwrap(),
+            Language::Eng
+        );
+    }
+}
@@ -0,0 +1,425 @@
+use std::{
+    borrow::Borrow,
+    collections::{BTreeMap, BTreeSet},
+};
+
+use strum::IntoEnumIterator;
+use unicode_segmentation::UnicodeSegmentation;
+
+use crate::{sentence::with_spans, Language, Sentence};
+
+/// The model used when no language is known.
+pub(crate) static DEFAULT: Punkt = Punkt::seeded(Seed::Dotted);
+
+/// Words seen followed by a period at least this many times may be abbreviations.
+const MIN_ABBREVIATION_COUNT: u32 = 2;
+
+/// Words seen starting a sentence at least this many times may be sentence starters.
+const MIN_STARTER_COUNT: u32 = 2;
+
+/// Abbreviations longer than this, in letters, need internal periods.
+const MAX_ABBREVIATION_LEN: usize = 5;
+
+/// Where abbreviations are known from before any training.
+#[derive(Clone, Copy, Debug)]
+enum Seed {
+    None,
+    /// The abbreviations of every language holding internal periods, as "e.g" or "z.B",
+    /// which are never words on their own.
+    Dotted,
+    Only(Language),
+}
+
+/// How often word types were seen in the training corpora, lowercase.
+#[derive(Clone, Debug)]
+struct Stats {
+    /// Occurrences followed by a period.
+    with_period: BTreeMap<String, u32>,
+    /// Occurrences not followed by a period.
+    without_period: BTreeMap<String, u32>,
+    /// Capitalized occurrences right after a sentence boundary.
+    starts: BTreeMap<String, u32>,
+    /// Capitalized occurrences within a sentence.
+    inner: BTreeMap<String, u32>,
+}
+
+impl Stats {
+    const fn new() -> Self {
+        Self {
+            with_period: BTreeMap::new(),
+            without_period: BTreeMap::new(),
+            starts: BTreeMap::new(),
+            inner: BTreeMap::new(),
+        }
+    }
+}
+
+/// An unsupervised sentence boundary model, in the spirit of
+/// [Punkt](https://doi.org/10.1162/coli.2006.32.4.485).
+///
+/// [Unicode sentence boundaries](https://www.unicode.org/reports/tr29/#Sentence_Boundaries)
+/// are kept, except after abbreviations and initials that are not followed by a sentence starter,
+/// as in "Dr. Smith" or "the U.S. Army".
+/// A single letter is only taken as an initial next to other initials or after a capitalized word,
+/// as in "J. R. Doe" or "John F. Kennedy", so that "plan B. Then" is two sentences.
+/// Abbreviations are known from the list of a single language,
+/// as "es" or "ex" are abbreviations in some languages and words in others,
+/// and more of them, along with sentence starters, can be learnt from plain text with [`Punkt::train`].
+#[derive(Clone, Debug)]
+pub struct Punkt {
+    seed: Seed,
+    abbreviations: BTreeSet<String>,
+    starters: BTreeSet<String>,
+    stats: Stats,
+}
+
+impl Default for Punkt {
+    /// A model knowing the abbreviations of every language with internal periods, as "e.g.".
+    #[inline]
+    fn default() -> Self {
+        Self::seeded(Seed::Dotted)
+    }
+}
+
+impl Punkt {
+    const fn seeded(seed: Seed) -> Self {
+        Self {
+            seed,
+            abbreviations: BTreeSet::new(),
+            starters: BTreeSet::new(),
+            stats: Stats::new(),
+        }
+    }
+
+    /// A model knowing no abbreviations, only what it is trained on.
+    #[inline]
+    #[must_use]
+    pub const fn empty() -> Self {
+        Self::seeded(Seed::None)
+    }
+
+    /// A model knowing the abbreviations of `lang`.
+    #[inline]
+    #[must_use]
+    pub const fn for_language(lang: Language) -> Self {
+        Self::seeded(Seed::Only(lang))
+    }
+
+    /// Learn abbreviations and sentence starters from `corpus`, a plain text.
+    ///
+    /// Training is cumulative, so a large corpus can be read and learnt in parts.
+    /// A word is taken as an abbreviation when it is short or has internal periods,
+    /// and is nearly always followed by a period.
+    /// A word is taken as a sentence starter when it is capitalized
+    /// more often after sentence boundaries than within sentences.
+    pub fn train(&mut self, corpus: &str) -> &mut Self {
+        for (word, period) in corpus.split_whitespace().map(split_period) {
+            let counts = if period {
+                &mut self.stats.with_period
+            } else {
+                &mut self.stats.without_period
+            };
+            *counts.entry(word.to_lowercase()).or_default() += 1;
+        }
+        let stats = &self.stats;
+        self.abbreviations = stats
+            .with_period
+            .iter()
+            .filter(|&(word, &with)| {
+                let without = stats.without_period.get(word).copied().unwrap_or_default();
+                is_abbreviated(word, with, without)
+            })
+            .map(|(word, _)| word.clone())
+            .collect();
+
+        let mut after_boundary = true;
+        for chunk in corpus.split_whitespace() {
+            let (word, period) = split_period(chunk);
+            if word.starts_with(char::is_uppercase) {
+                let counts = if after_boundary {
+                    &mut self.stats.starts
+                } else {
+                    &mut self.stats.inner
+                };
+                *counts.entry(word.to_lowercase()).or_default() += 1;
+            }
+            after_boundary = if period {
+                !self.is_abbreviation(word)
+            } else {
+                chunk
+                    .trim_end_matches(is_closing)
+                    .ends_with(['!', '?', '…'])
+            };
+        }
+        let stats = &self.stats;
+        self.starters = stats
+            .starts
+            .iter()
+            .filter(|&(word, &starts)| {
+                let inner = stats.inner.get(word).copied().unwrap_or_default();
+                starts >= MIN_STARTER_COUNT && starts > inner
+            })
+            .map(|(word, _)| word.clone())
+            .collect();
+        self
+    }
+
+    /// Whether `word`, without its final period, is a known abbreviation.
+    #[must_use]
+    pub fn is_abbreviation(&self, word: &str) -> bool {
+        let word = word.to_lowercase();
+        let known = |lang| {
+            crate::abbreviations::get(lang)
+                .binary_search(&&*word)
+                .is_ok()
+        };
+        self.abbreviations.contains(&word)
+            || match self.seed {
+                Seed::None => false,
+                Seed::Dotted => word.contains('.') && Language::iter().any(known),
+                Seed::Only(lang) => known(lang),
+            }
+    }
+
+    /// Whether `word` was learnt to start sentences.
+    #[inline]
+    fn is_starter(&self, word: &str) -> bool {
+        self.starters.contains(&word.to_lowercase())
+    }
+
+    /// Whether `sentence` goes on in `next`, despite the Unicode sentence boundary between them.
+    fn continues(&self, sentence: &str, next: &str) -> bool {
+        let Some(before) = sentence.trim_end().strip_suffix('.') else {
+            return false;
+        };
+        let mut words = before.split_whitespace().rev();
+        let Some(word) = words.next() else {
+            return false;
+        };
+        if before.ends_with(char::is_whitespace) {
+            return false;
+        }
+        let word = word.trim_start_matches(is_opening);
+        let abbreviation = self.is_abbreviation(word);
+        if !abbreviation && !is_initialism(word) {
+            return false;
+        }
+        let chunk = next
+            .split_whitespace()
+            .next()
+            .unwrap_or_default()
+            .trim_start_matches(is_opening);
+        let next = chunk.trim_end_matches(|c: char| !c.is_alphanumeric());
+        // NOTE: a lone letter may as well be a word ending the sentence, as in "plan B."
+        if !abbreviation && !word.contains('.') {
+            let initials = chunk.ends_with('.') && is_initialism(next);
+            let named = words.next().is_some_and(|previous| {
+                let previous = previous.trim_start_matches(is_opening);
+                previous.starts_with(char::is_uppercase)
+                    || previous.strip_suffix('.').is_some_and(is_initialism)
+            });
+            if !named && !initials {
+                return false;
+            }
+        }
+        match next.chars().next() {
+            Some(c) if c.is_uppercase() => !self.is_starter(next),
+            Some(_) => true,
+            None => false,
+        }
+    }
+
+    /// Split `text` into sentences, along with where each one starts in bytes.
+    ///
+    /// Entities such as URLs are never split.
+    #[inline]
+    pub(crate) fn sentence_bound_indices<'punkt, 'text: 'punkt>(
+        &'punkt self,
+        text: &'text str,
+    ) -> impl Iterator<Item = (usize, &'text str)> + 'punkt {
+        bound_indices(self, text)
+    }
+
+    /// Split `text` into sentences.
+    #[inline]
+    pub fn sentences<'punkt, 'text: 'punkt>(
+        &'punkt self,
+        text: &'text str,
+    ) -> impl Iterator<Item = Sentence<'text>> + 'punkt {
+        with_spans(self.sentence_bound_indices(text))
+    }
+}
+
+/// Split `text` into sentences with `punkt`, along with where each one starts in bytes.
+///
+/// The model is borrowed or owned, so that one can be made for the iterator alone.
+/// Entities such as URLs are never split.
+pub(crate) fn bound_indices<'punkt, 'text: 'punkt, P>(
+    punkt: P,
+    text: &'text str,
+) -> impl Iterator<Item = (usize, &'text str)> + 'punkt
+where
+    P: Borrow<Punkt> + 'punkt,
+{
+    let mut bounds = text.split_sentence_bound_indices().peekable();
+    let entities: Vec<_> = crate::entity::find(text).collect();
+    std::iter::from_fn(move || {
+        let (start, sentence) = bounds.next()?;
+        let mut end = start + sentence.len();
+        while let Some(&(next_start, next)) = bounds.peek() {
+            // NOTE: URLs and the like may hold sentence terminators, as in "a.com/?q=1"
+            // NOTE: entities are sorted and disjoint, so their ends are sorted as well
+            let within = entities
+                .get(entities.partition_point(|entity| entity.end <= end))
+                .is_some_and(|entity| entity.start < end);
+            if !within && !punkt.borrow().continues(&text[start..end], next) {
+                break;
+            }
+            end = next_start + next.len();
+            bounds.next();
+        }
+        Some((start, &text[start..end]))
+    })
+}
+
+#[inline]
+fn is_opening(c: char) -> bool {
+    matches!(
+        c,
+        '(' | '[' | '{' | '"' | '\'' | '“' | '‘' | '«' | '¿' | '¡'
+    )
+}
+
+#[inline]
+fn is_closing(c: char) -> bool {
+    matches!(c, ')' | ']' | '}' | '"' | '\'' | '”' | '’' | '»')
+}
+
+/// Strip the punctuation around a whitespace-separated `chunk`,
+/// telling whether the word was followed by a period.
+fn split_period(chunk: &str) -> (&str, bool) {
+    let chunk = chunk
+        .trim_start_matches(is_opening)
+        .trim_end_matches(is_closing);
+    match chunk.strip_suffix('.') {
+        Some(word) if !word.ends_with('.') => (word, true),
+        _ => (
+            chunk.trim_end_matches(|c: char| !c.is_alphanumeric()),
+            false,
+        ),
+    }
+}
+
+/// Whether a word is a single letter, or letters joined by periods, as in "J" or "U.S".
+#[inline]
+fn is_initialism(word: &str) -> bool {
+    word.split('.').all(|part| {
+        let mut chars = part.chars();
+        matches!((chars.next(), chars.next()), (Some(c), None) if c.is_alphabetic())
+    })
+}
+
+/// Whether a word seen `with` and `without` a following period looks abbreviated.
+fn is_abbreviated(word: &str, with: u32, without: u32) -> bool {
+    let letters = word.chars().filter(|c| c.is_alphabetic()).count();
+    letters > 0
+        && with >= MIN_ABBREVIATION_COUNT
+        // NOTE: at least nine in ten occurrences are followed by a period
+        && with * 10 >= (with + without) * 9
+        && (word.contains('.') || letters <= MAX_ABBREVIATION_LEN)
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+
+    fn split<'text>(punkt: &Punkt, text: &'text str) -> Vec<&'text str> {
+        punkt
+            .sentences(text)
+            .map(|sentence| sentence.text)
+            .collect()
+    }
+
+    #[cfg(feature = "english")]
+    #[test]
+    fn abbreviations_and_initials() {
+        let punkt = Punkt::for_language(Language::Eng);
+        assert_eq!(
+            split(
+                &punkt,
+                "Dr. Smith met J. R. Doe in the U.S. Army. They talked."
+            ),
+            ["Dr. Smith met J. R. Doe in the U.S. Army. ", "They talked."]
+        );
+        assert_eq!(
+            split(
+                &punkt,
+                "It costs 3.50 dollars, e.g. Too much. The answer is no. Ok"
+            ),
+            [
+                "It costs 3.50 dollars, e.g. Too much. ",
+                "The answer is no. ",
+                "Ok"
+            ]
+        );
+        assert_eq!(
+            split(
+                &punkt,
+                "I saw plan B. Then we left. John F. Kennedy was there."
+            ),
+            [
+                "I saw plan B. ",
+                "Then we left. ",
+                "John F. Kennedy was there."
+            ]
+        );
+        assert!(punkt.is_abbreviation("Dr"));
+        assert!(!punkt.is_abbreviation("z.B"));
+        assert!(!Punkt::empty().is_abbreviation("Dr"));
+    }
+
+    #[test]
+    fn no_pooled_abbreviations() {
+        let punkt = Punkt::default();
+        assert_eq!(
+            split(&punkt, "Así es. Gracias por todo."),
+            ["Así es. ", "Gracias por todo."]
+        );
+        assert_eq!(
+            split(&punkt, "He was a co. Then he left."),
+            ["He was a co. ", "Then he left."]
+        );
+        assert_eq!(
+            split(&punkt, "See e.g. Smith and the U.S. Army."),
+            ["See e.g. Smith and the U.S. Army."]
+        );
+        assert!(!punkt.is_abbreviation("Dr"));
+        assert!(!punkt.is_abbreviation("es"));
+        #[cfg(feature = "german")]
+        assert!(punkt.is_abbreviation("z.B"));
+    }
+
+    #[test]
+    fn train_on_corpus() {
+        let corpus = "The cat sat on the mat. The dog sat on Tab. 3 of the plan. \
+            See Tab. 4 as well. It was fine. The end is near. Smith saw it.";
+        let mut punkt = Punkt::empty();
+        assert_eq!(
+            split(&punkt, "See Tab. The table."),
+            ["See Tab. ", "The table."]
+        );
+        punkt.train(corpus);
+        assert!(punkt.is_abbreviation("tab"));
+        assert!(!punkt.is_abbreviation("mat"));
+        assert!(punkt.is_starter("The"));
+        assert!(!punkt.is_starter("Smith"));
+
+        // NOTE: an abbreviation still ends a sentence before a sentence starter
+        assert_eq!(
+            split(&punkt, "See Tab. The table. See Tab. Four."),
+            ["See Tab. ", "The table. ", "See Tab. Four."]
+        );
+    }
+}
@@ -0,0 +1,226 @@
+use std::collections::HashMap;
+
+use crate::Language;
+
+/// The writing systems of Chinese and Japanese, which don't separate words with spaces.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum Script {
+    Han,
+    Hiragana,
+    Katakana,
+}
+
+impl Script {
+    #[inline]
+    const fn of(c: char) -> Option<Self> {
+        match c {
+            '\u{3005}'
+            | '\u{3007}'
+            | '\u{3400}'..='\u{4dbf}'
+            | '\u{4e00}'..='\u{9fff}'
+            | '\u{f900}'..='\u{faff}'
+            | '\u{20000}'..='\u{2fa1f}' => Some(Self::Han),
+            '\u{3041}'..='\u{309f}' => Some(Self::Hiragana),
+            '\u{30a0}'..='\u{30ff}' | '\u{31f0}'..='\u{31ff}' | '\u{ff66}'..='\u{ff9f}' => {
+                Some(Self::Katakana)
+            }
+            _ => None,
+        }
+    }
+}
+
+/// The cost of a char, or of a run of a single script, that is not a known word,
+/// as in ICU.
+const UNKNOWN_COST: u32 = 255;
+
+/// Words of Chinese and Japanese, with the cost of using each in a segmentation.
+///
+/// This is the dictionary ICU uses to find word boundaries in both languages,
+/// see `data/cjdict.txt` for its source and licence.
+struct Lexicon {
+    costs: HashMap<&'static str, u32>,
+    /// The length of the longest word, in chars.
+    max_len: usize,
+}
+
+impl Lexicon {
+    /// Parse a bundled lexicon, one word per line followed by its cost.
+    #[cfg_attr(not(any(feature = "mandarin", feature = "japanese")), allow(dead_code))]
+    fn new(data: &'static str) -> Self {
+        let costs: HashMap<_, _> = data
+            .lines()
+            .filter(|line| !line.starts_with('#'))
+            .filter_map(|line| {
+                let (word, cost) = line.split_once(' ')?;
+                Some((word, cost.trim().parse().ok()?))
+            })
+            .collect();
+        let max_len = costs
+            .keys()
+            .map(|word| word.chars().count())
+            .max()
+            .unwrap_or(1);
+        Self { costs, max_len }
+    }
+
+    /// Split `text` into its most likely words, keeping anything not in Chinese or Japanese whole.
+    ///
+    /// With `runs`, runs of a single script may be taken as unknown words, as in Japanese.
+    fn segment<'text>(&self, text: &'text str, runs: bool) -> Vec<&'text str> {
+        let mut words = Vec::new();
+        let mut rest = text;
+        while let Some(c) = rest.chars().next() {
+            let cjk = Script::of(c).is_some();
+            let len = rest
+                .find(|c| Script::of(c).is_some() != cjk)
+                .unwrap_or(rest.len());
+            let (part, tail) = rest.split_at(len);
+            if cjk {
+                words.extend(self.viterbi(part, runs));
+            } else {
+                words.push(part);
+            }
+            rest = tail;
+        }
+        words
+    }
+
+    /// Find the cheapest split of `text`, which is entirely in Chinese or Japanese.
+    fn viterbi<'text>(&self, text: &'text str, runs: bool) -> Vec<&'text str> {
+        let bounds: Vec<_> = text
+            .char_indices()
+            .map(|(index, _)| index)
+            .chain([text.len()])
+            .collect();
+        let scripts: Vec<_> = text.chars().map(Script::of).collect();
+        let n = scripts.len();
+
+        // NOTE: the best cost of splitting the first `i` chars, and where its last word starts
+        let mut best = vec![(u32::MAX, 0); n + 1];
+        best[0].0 = 0;
+        for i in 0..n {
+            let base = best[i].0;
+            let mut relax = |j: usize, cost: u32| {
+                if base + cost < best[j].0 {
+                    best[j] = (base + cost, i);
+                }
+            };
+            for j in i + 1..=n.min(i + self.max_len) {
+                if let Some(&cost) = self.costs.get(&text[bounds[i]..bounds[j]]) {
+                    relax(j, cost);
+                }
+            }
+            relax(i + 1, UNKNOWN_COST);
+            if runs {
+                let run = scripts[i..]
+                    .iter()
+                    .take_while(|&&script| script == scripts[i])
+                    .count();
+                relax(i + run, UNKNOWN_COST);
+            }
+        }
+
+        let mut words = Vec::new();
+        let mut end = n;
+        while end > 0 {
+            let start = best[end].1;
+            words.push(&text[bounds[start]..bounds[end]]);
+            end = start;
+        }
+        words.reverse();
+        words
+    }
+}
+
+/// The lexicon of `lang`, if its words are not separated by spaces,
+/// along with whether runs of a single script may be taken as unknown words.
+#[cfg(any(feature = "mandarin", feature = "japanese"))]
+fn lexicon(lang: Language) -> Option<(&'static Lexicon, bool)> {
+    static LEXICON: std::sync::OnceLock<Lexicon> = std::sync::OnceLock::new();
+
+    let runs = match lang {
+        #[cfg(feature = "mandarin")]
+        Language::Cmn => false,
+        #[cfg(feature = "japanese")]
+        Language::Jpn => true,
+        #[allow(unreachable_patterns)]
+        _ => return None,
+    };
+    let lexicon = LEXICON.get_or_init(|| Lexicon::new(include_str!("../data/cjdict.txt")));
+    Some((lexicon, runs))
+}
+
+#[cfg(not(any(feature = "mandarin", feature = "japanese")))]
+#[inline]
+const fn lexicon(_: Language) -> Option<(&'static Lexicon, bool)> {
+    None
+}
+
+/// Split `word` into the most likely words of the lexicon of `lang`.
+///
+/// This returns `None` unless `lang` is Mandarin or Japanese and `word` holds any of their scripts.
+/// Japanese words not in the lexicon are told apart by changes of script,
+/// as between kanji and katakana.
+pub(crate) fn segment(lang: Language, word: &str) -> Option<Vec<&str>> {
+    let (lexicon, runs) = lexicon(lang)?;
+    word.contains(|c| Script::of(c).is_some())
+        .then(|| lexicon.segment(word, runs))
+}
+
+/// Whether `word` holds Chinese or Japanese but is not a word of the lexicon of `lang`,
+/// as when it was split off as an unknown char or run of a script.
+pub(crate) fn is_unknown(lang: Language, word: &str) -> bool {
+    lexicon(lang).is_some_and(|(lexicon, _)| {
+        word.contains(|c| Script::of(c).is_some()) && !lexicon.costs.contains_key(word)
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+
+    #[cfg(feature = "mandarin")]
+    #[test]
+    fn segment_mandarin() {
+        assert_eq!(
+            segment(Language::Cmn, "我们是学生"),
+            Some(vec!["我们", "是", "学生"])
+        );
+        assert_eq!(
+            segment(Language::Cmn, "他们在工作"),
+            Some(vec!["他们", "在", "工作"])
+        );
+        assert_eq!(
+            segment(Language::Cmn, "我们明天去北京大学看朋友"),
+            Some(vec!["我们", "明天", "去", "北京", "大学", "看", "朋友"])
+        );
+        assert_eq!(segment(Language::Cmn, "学生abc"), Some(vec!["学生", "abc"]));
+        assert_eq!(segment(Language::Cmn, "abc"), None);
+    }
+
+    #[cfg(feature = "japanese")]
+    #[test]
+    fn segment_japanese() {
+        assert_eq!(
+            segment(Language::Jpn, "これはペンです"),
+            Some(vec!["これ", "は", "ペン", "です"])
+        );
+        assert_eq!(
+            segment(Language::Jpn, "東京都に住んでいます"),
+            Some(vec!["東京", "都", "に", "住", "んで", "い", "ます"])
+        );
+        // NOTE: unknown katakana words are kept whole
+        assert_eq!(
+            segment(Language::Jpn, "私はラーメンが好き"),
+            Some(vec!["私", "は", "ラーメン", "が", "好き"])
+        );
+    }
+
+    #[cfg(feature = "english")]
+    #[test]
+    fn leave_other_languages() {
+        assert_eq!(segment(Language::Eng, "我们"), None);
+    }
+}
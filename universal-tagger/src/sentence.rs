@@ -0,0 +1,81 @@
+use crate::{
+    span::{token_spans_from, Offset},
+    Span, TokenInfo,
+};
+
+/// A sentence of a text, following the
+/// [Unicode sentence boundaries](https://www.unicode.org/reports/tr29/#Sentence_Boundaries)
+/// except after abbreviations, as told by a [`Punkt`](crate::Punkt) model.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Sentence<'text> {
+    /// The sentence, including any trailing spaces.
+    pub text: &'text str,
+    pub span: Span,
+}
+
+impl<'text> Sentence<'text> {
+    /// Tokenize the sentence, with spans within the whole text.
+    #[inline]
+    pub fn tokens(&self) -> impl Iterator<Item = TokenInfo<'text>> {
+        token_spans_from(self.text, self.span.start)
+    }
+}
+
+/// Split `text` into sentences, knowing only the abbreviations with internal periods, as "e.g.".
+///
+/// See [`Tagger::sentences`](crate::Tagger::sentences) to know those of a language.
+#[inline]
+pub fn sentences(text: &str) -> impl Iterator<Item = Sentence<'_>> {
+    crate::punkt::DEFAULT.sentences(text)
+}
+
+/// Attach spans to consecutive `sentences`, which start at the beginning of the text.
+#[inline]
+pub(crate) fn with_spans<'text>(
+    sentences: impl Iterator<Item = (usize, &'text str)>,
+) -> impl Iterator<Item = Sentence<'text>> {
+    let mut offset = Offset::default();
+    sentences.map(move |(_, sentence)| {
+        let start = offset;
+        offset.advance(sentence);
+        Sentence {
+            text: sentence,
+            span: Span { start, end: offset },
+        }
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+    use crate::Token;
+
+    #[test]
+    fn split_sentences() {
+        let text = "Ceci n'est pas une pipe. This is not a pipe!\nOk? I.e. Who.";
+        let found: Vec<_> = sentences(text).collect();
+        let texts: Vec<_> = found.iter().map(|sentence| sentence.text).collect();
+        assert_eq!(
+            texts,
+            [
+                "Ceci n'est pas une pipe. ",
+                "This is not a pipe!\n",
+                "Ok? ",
+                "I.e. Who."
+            ]
+        );
+
+        for sentence in &found {
+            assert_eq!(&text[sentence.span.bytes()], sentence.text);
+        }
+        assert_eq!(found[2].span.start.line, 1);
+
+        let tokens: Vec<_> = found[1].tokens().collect();
+        assert_eq!(tokens[0].token, Token::Letter("This"));
+        assert_eq!(tokens[0].span.bytes(), 25..29);
+        let last = tokens.last().unwrap();
+        assert_eq!(&text[last.span.bytes()], "\n");
+    }
+}
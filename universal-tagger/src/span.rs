@@ -0,0 +1,182 @@
+use std::ops::Range;
+
+use crate::{token_positions, Token};
+
+/// A location in a text, counted in several units at once.
+///
+/// Lines and columns start at zero, and lines are broken at line feeds.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Offset {
+    /// Offset in bytes, for slicing the text.
+    pub byte: usize,
+    /// Offset in Unicode scalar values.
+    pub char: usize,
+    /// Offset in UTF-16 code units, as used by editors and the Language Server Protocol.
+    pub utf16: usize,
+    /// Line number.
+    pub line: usize,
+    /// Column within the line, in Unicode scalar values.
+    pub column: usize,
+    /// Column within the line, in UTF-16 code units.
+    pub utf16_column: usize,
+}
+
+impl Offset {
+    /// Advance the offset past `text`.
+    #[inline]
+    pub(crate) fn advance(&mut self, text: &str) {
+        for c in text.chars() {
+            self.byte += c.len_utf8();
+            self.char += 1;
+            self.utf16 += c.len_utf16();
+            if c == '\n' {
+                self.line += 1;
+                self.column = 0;
+                self.utf16_column = 0;
+            } else {
+                self.column += 1;
+                self.utf16_column += c.len_utf16();
+            }
+        }
+    }
+}
+
+/// The range of a text covered by something, such as a token.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Span {
+    pub start: Offset,
+    pub end: Offset,
+}
+
+impl Span {
+    /// The range in bytes, for slicing the text.
+    #[inline]
+    #[must_use]
+    pub const fn bytes(&self) -> Range<usize> {
+        self.start.byte..self.end.byte
+    }
+
+    /// The range in Unicode scalar values.
+    #[inline]
+    #[must_use]
+    pub const fn chars(&self) -> Range<usize> {
+        self.start.char..self.end.char
+    }
+
+    /// The range in UTF-16 code units.
+    #[inline]
+    #[must_use]
+    pub const fn utf16(&self) -> Range<usize> {
+        self.start.utf16..self.end.utf16
+    }
+}
+
+/// Spans of increasing, non-overlapping byte ranges of a text, computed in a single pass.
+pub(crate) struct Spanner<'text> {
+    text: &'text str,
+    offset: Offset,
+}
+
+impl<'text> Spanner<'text> {
+    #[inline]
+    pub(crate) const fn new(text: &'text str) -> Self {
+        Self {
+            text,
+            offset: Offset {
+                byte: 0,
+                char: 0,
+                utf16: 0,
+                line: 0,
+                column: 0,
+                utf16_column: 0,
+            },
+        }
+    }
+
+    /// The span of `range`, which must not start before the end of the previous one.
+    pub(crate) fn span(&mut self, range: Range<usize>) -> Span {
+        self.offset
+            .advance(&self.text[self.offset.byte..range.start]);
+        let start = self.offset;
+        self.offset.advance(&self.text[range]);
+        Span {
+            start,
+            end: self.offset,
+        }
+    }
+}
+
+/// A token along with where it is in the text.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct TokenInfo<'text> {
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    pub token: Token<'text>,
+    pub span: Span,
+}
+
+/// Tokenize `text` like [`token_positions`], along with the span of each token.
+///
+/// Spans are computed incrementally, in a single pass over the text.
+#[inline]
+pub fn token_spans(text: &str) -> impl Iterator<Item = TokenInfo<'_>> {
+    token_spans_from(text, Offset::default())
+}
+
+/// Tokenize `text`, which starts at `offset` within a larger text.
+#[inline]
+pub(crate) fn token_spans_from(
+    text: &str,
+    mut offset: Offset,
+) -> impl Iterator<Item = TokenInfo<'_>> {
+    token_positions(text).map(move |(_, token)| {
+        let start = offset;
+        offset.advance(token.as_ref());
+        TokenInfo {
+            token,
+            span: Span { start, end: offset },
+        }
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+
+    #[test]
+    fn spans() {
+        let text = "naïve 𝔘nicode\nok";
+        let infos: Vec<_> = token_spans(text).collect();
+        let tokens: Vec<_> = infos.iter().map(|info| info.token.as_ref()).collect();
+        assert_eq!(tokens, ["naïve", " ", "𝔘nicode", "\n", "ok"]);
+
+        for info in &infos {
+            assert_eq!(&text[info.span.bytes()], info.token.as_ref());
+        }
+
+        let unicode = infos[2].span;
+        assert_eq!(unicode.bytes(), 7..17);
+        assert_eq!(unicode.chars(), 6..13);
+        // NOTE: "𝔘" is outside the Basic Multilingual Plane
+        assert_eq!(unicode.utf16(), 6..14);
+
+        let ok = infos[4].span;
+        assert_eq!(
+            ok.start,
+            Offset {
+                byte: 18,
+                char: 14,
+                utf16: 15,
+                line: 1,
+                column: 0,
+                utf16_column: 0,
+            }
+        );
+        assert_eq!((ok.end.line, ok.end.column, ok.end.utf16_column), (1, 2, 2));
+        assert_eq!(infos[3].span.start.utf16_column, 14);
+    }
+}
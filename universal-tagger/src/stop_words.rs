@@ -9,6 +9,21 @@ pub fn get(lang: Language) -> BTreeSet<UniCase<String>> {
     let lang: stop_words::LANGUAGE = lang.into();
     stop_words::get(lang)
         .into_iter()
-        .map(UniCase::new)
+        // NOTE: words come serialized as JSON strings, quotes included
+        .map(|word| UniCase::new(word.trim_matches('"').to_owned()))
         .collect()
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[cfg(feature = "english")]
+    #[test]
+    fn unquoted_words() {
+        let stop_words = get(Language::Eng);
+        assert!(stop_words.contains(&UniCase::new("the".to_owned())));
+        assert!(stop_words.contains(&UniCase::new("The".to_owned())));
+        assert!(!stop_words.contains(&UniCase::new("\"the\"".to_owned())));
+    }
+}
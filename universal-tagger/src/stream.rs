@@ -0,0 +1,441 @@
+use std::{
+    collections::VecDeque,
+    io::{self, Read},
+    vec::Drain,
+};
+
+use itertools::Either;
+use unicode_categories::UnicodeCategories;
+
+use crate::{token_positions, Language, Offset, Span, TokenInfoBuf, Tokenizer};
+
+/// The size of the chunks read by a [`TokenStream`].
+const CHUNK_LEN: usize = 64 * 1024;
+
+/// The length in bytes past which text is cut even where its tokens may change,
+/// so that memory use stays bounded.
+const MAX_TEXT_LEN: usize = 1024 * 1024;
+
+/// Tokenize text arriving in chunks of bytes, as from a socket or an async stream.
+///
+/// Chunks may end anywhere, even within a UTF-8 sequence.
+/// Text is cut between two words of letters and digits, as the tokens around those
+/// can no longer change, so that they are the same as those of the whole text at once,
+/// with spans within the whole text.
+/// Past a megabyte without such words, text is cut at the last space, or else between tokens,
+/// so that tokens there may differ.
+///
+/// See [`TokenStream`] for reading from an [`io::Read`].
+#[derive(Clone, Debug)]
+pub struct StreamTokenizer {
+    tokenizer: Option<Tokenizer>,
+    /// Text not yet tokenized.
+    text: String,
+    /// The start of a UTF-8 sequence split across chunks.
+    bytes: Vec<u8>,
+    /// Where `text` starts in the whole stream.
+    offset: Offset,
+    /// The length of `text` already searched for cuts.
+    scanned: usize,
+    tokens: Vec<TokenInfoBuf>,
+    max_text_len: usize,
+}
+
+impl Default for StreamTokenizer {
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl StreamTokenizer {
+    /// Tokenize like [`token_positions`].
+    #[inline]
+    #[must_use]
+    pub fn new() -> Self {
+        Self {
+            tokenizer: None,
+            text: String::new(),
+            bytes: Vec::new(),
+            offset: Offset::default(),
+            scanned: 0,
+            tokens: Vec::new(),
+            max_text_len: MAX_TEXT_LEN,
+        }
+    }
+
+    /// Tokenize like [`Tokenizer::token_positions`], knowing the language of the text.
+    #[inline]
+    #[must_use]
+    pub fn for_language(lang: Language) -> Self {
+        Self {
+            tokenizer: Some(Tokenizer { lang }),
+            ..Self::new()
+        }
+    }
+
+    /// Feed the next chunk of the stream, returning the tokens that became complete.
+    ///
+    /// # Errors
+    ///
+    /// Fails with [`io::ErrorKind::InvalidData`] if the stream is not valid UTF-8.
+    pub fn push(&mut self, bytes: &[u8]) -> io::Result<Drain<'_, TokenInfoBuf>> {
+        self.decode(bytes)?;
+        self.flush(false);
+        Ok(self.tokens.drain(..))
+    }
+
+    /// End the stream, returning the remaining tokens.
+    ///
+    /// # Errors
+    ///
+    /// Fails with [`io::ErrorKind::InvalidData`] if the stream ends within a UTF-8 sequence.
+    pub fn finish(&mut self) -> io::Result<Drain<'_, TokenInfoBuf>> {
+        if !self.bytes.is_empty() {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidData,
+                "stream ended within a UTF-8 sequence",
+            ));
+        }
+        self.flush(true);
+        Ok(self.tokens.drain(..))
+    }
+
+    /// Append `bytes` to the text, keeping the start of a UTF-8 sequence they may end with.
+    fn decode(&mut self, mut bytes: &[u8]) -> io::Result<()> {
+        let invalid = |error| io::Error::new(io::ErrorKind::InvalidData, error);
+
+        // NOTE: a UTF-8 sequence is at most four bytes long
+        while !self.bytes.is_empty() && !bytes.is_empty() {
+            self.bytes.push(bytes[0]);
+            bytes = &bytes[1..];
+            match std::str::from_utf8(&self.bytes) {
+                Ok(c) => {
+                    self.text.push_str(c);
+                    self.bytes.clear();
+                }
+                Err(error) if error.error_len().is_some() || self.bytes.len() == 4 => {
+                    return Err(invalid(error));
+                }
+                Err(_) => {}
+            }
+        }
+
+        let valid = match std::str::from_utf8(bytes) {
+            Ok(text) => {
+                self.text.push_str(text);
+                return Ok(());
+            }
+            Err(error) if error.error_len().is_none() => error.valid_up_to(),
+            Err(error) => return Err(invalid(error)),
+        };
+        let (text, rest) = bytes.split_at(valid);
+        self.text
+            .push_str(std::str::from_utf8(text).map_err(invalid)?);
+        self.bytes.extend_from_slice(rest);
+        Ok(())
+    }
+
+    /// The start of the last word of letters and digits following another one,
+    /// looking only at the words completed since the last search.
+    fn cut(&mut self) -> Option<usize> {
+        let text = self.text.as_str();
+        let scanned = std::mem::replace(&mut self.scanned, text.len());
+        // NOTE: the last word may go on
+        let mut end = scanned + text[scanned..].rfind(char::is_whitespace)?;
+        loop {
+            let before = text[..end].trim_end();
+            if before.len() < scanned {
+                return None;
+            }
+            let start = word_start(before);
+            let previous = text[..start].trim_end();
+            if previous.is_empty() {
+                return None;
+            }
+            if is_plain(&before[start..]) && is_plain(&previous[word_start(previous)..]) {
+                return Some(start);
+            }
+            end = start;
+        }
+    }
+
+    /// Tokenize the text up to the latest cut, or all of it at the end of the stream.
+    fn flush(&mut self, end: bool) {
+        let cut = self.cut();
+        let text = self.text.as_str();
+        let tokenize = |text| {
+            match self.tokenizer {
+                Some(tokenizer) => Either::Left(tokenizer.token_positions(text)),
+                None => Either::Right(token_positions(text)),
+            }
+            .map(|(position, token)| (position.into_inner(), token))
+        };
+        let tokens: Vec<_> = match cut {
+            _ if end => tokenize(text).collect(),
+            Some(cut) => tokenize(&text[..cut]).collect(),
+            // NOTE: the last word may go on, and so may the last token of text without spaces
+            None if text.len() > self.max_text_len => match word_start(text.trim_end()) {
+                0 => {
+                    let mut tokens: Vec<_> = tokenize(text).collect();
+                    if tokens.len() > 1 {
+                        tokens.pop();
+                    }
+                    tokens
+                }
+                start => tokenize(&text[..start]).collect(),
+            },
+            None => return,
+        };
+        let cut = tokens
+            .last()
+            .map_or(0, |(start, token)| start + token.as_ref().len());
+
+        let offset = &mut self.offset;
+        self.tokens.extend(tokens.into_iter().map(|(_, token)| {
+            let start = *offset;
+            offset.advance(token.as_ref());
+            TokenInfoBuf {
+                token: token.into(),
+                span: Span {
+                    start,
+                    end: *offset,
+                },
+            }
+        }));
+        self.text.drain(..cut);
+        self.scanned = self.text.len();
+    }
+}
+
+/// Where the last word of `text` starts, after its last whitespace.
+#[inline]
+fn word_start(text: &str) -> usize {
+    text.rfind(char::is_whitespace).map_or(0, |index| {
+        index + text[index..].chars().next().map_or(0, char::len_utf8)
+    })
+}
+
+/// Whether `word` is made of letters and digits, starting with a letter.
+///
+/// Text can be cut between two such words without changing its tokens,
+/// as they hold no sentence terminators, are not part of any entity,
+/// and end the look-ahead of the terminators before them.
+#[inline]
+fn is_plain(word: &str) -> bool {
+    word.starts_with(|c: char| c.is_letter()) && word.chars().all(char::is_alphanumeric)
+}
+
+/// Owned tokens read from a stream, with spans within the whole stream.
+///
+/// This reads in chunks, so that texts that don't fit in memory can be tokenized.
+/// Any [`io::BufRead`] works as well.
+/// See [`StreamTokenizer`] for how chunks are put together,
+/// and for streams that are not [`io::Read`], such as async ones.
+#[derive(Debug)]
+pub struct TokenStream<R> {
+    reader: R,
+    tokenizer: StreamTokenizer,
+    chunk: Box<[u8]>,
+    tokens: VecDeque<TokenInfoBuf>,
+    done: bool,
+}
+
+impl<R: Read> TokenStream<R> {
+    #[inline]
+    fn new(reader: R, tokenizer: StreamTokenizer) -> Self {
+        Self {
+            reader,
+            tokenizer,
+            chunk: vec![0; CHUNK_LEN].into_boxed_slice(),
+            tokens: VecDeque::new(),
+            done: false,
+        }
+    }
+}
+
+impl<R: Read> Iterator for TokenStream<R> {
+    type Item = io::Result<TokenInfoBuf>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            if let Some(token) = self.tokens.pop_front() {
+                return Some(Ok(token));
+            }
+            if self.done {
+                return None;
+            }
+            let tokens = match self.reader.read(&mut self.chunk) {
+                Ok(0) => {
+                    self.done = true;
+                    self.tokenizer.finish()
+                }
+                Ok(len) => self.tokenizer.push(&self.chunk[..len]),
+                Err(error) if error.kind() == io::ErrorKind::Interrupted => continue,
+                Err(error) => Err(error),
+            };
+            match tokens {
+                Ok(tokens) => self.tokens.extend(tokens),
+                Err(error) => {
+                    self.done = true;
+                    return Some(Err(error));
+                }
+            }
+        }
+    }
+}
+
+/// Tokenize the text read from `reader` like [`token_spans`](crate::token_spans),
+/// without reading it whole into memory.
+///
+/// See [`TokenStream`].
+#[inline]
+pub fn token_stream<R: Read>(reader: R) -> TokenStream<R> {
+    TokenStream::new(reader, StreamTokenizer::new())
+}
+
+impl Tokenizer {
+    /// Tokenize the text read from `reader`, without reading it whole into memory.
+    ///
+    /// See [`TokenStream`].
+    #[inline]
+    pub fn stream<R: Read>(&self, reader: R) -> TokenStream<R> {
+        TokenStream::new(reader, StreamTokenizer::for_language(self.lang))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+    use proptest::prelude::*;
+
+    use super::*;
+
+    /// A reader giving at most `len` bytes at a time.
+    struct Chunks<'bytes> {
+        bytes: &'bytes [u8],
+        len: usize,
+    }
+
+    impl Read for Chunks<'_> {
+        fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+            let len = self.len.min(buf.len()).min(self.bytes.len());
+            buf[..len].copy_from_slice(&self.bytes[..len]);
+            self.bytes = &self.bytes[len..];
+            Ok(len)
+        }
+    }
+
+    fn stream(text: &str, len: usize) -> Vec<TokenInfoBuf> {
+        let chunks = Chunks {
+            bytes: text.as_bytes(),
+            len,
+        };
+        token_stream(chunks).collect::<io::Result<_>>().unwrap()
+    }
+
+    fn whole(text: &str) -> Vec<TokenInfoBuf> {
+        crate::token_spans(text).map(TokenInfoBuf::from).collect()
+    }
+
+    proptest! {
+        #[test]
+        fn stream_any_text(text in "\\PC*", len in 1..16_usize) {
+            prop_assert_eq!(stream(&text, len), whole(&text));
+        }
+
+        #[test]
+        fn stream_any_sentences(
+            text in "((Dr|[A-Za-z]{1,4}|\\PC{1,3}|[0-9.,]{1,4}|/a\\.b)[.?!,;\"]{0,2}[ \n]{0,2}){0,24}",
+            len in 1..16_usize,
+        ) {
+            prop_assert_eq!(stream(&text, len), whole(&text));
+        }
+    }
+
+    #[test]
+    fn stream_sentences() {
+        let text = "Dr. Smith went to www.example.com/a.b?c=d. Ok!\n\
+                    Then he said «ça va» 👩‍💻, and left at 10:30.\n";
+        for len in [1, 2, 3, 5, 7, 64, 1024] {
+            assert_eq!(stream(text, len), whole(text), "in chunks of {len}");
+        }
+
+        let tokens = stream(text, 4);
+        let last = tokens.last().unwrap();
+        assert_eq!(last.span.end.line, 2);
+        assert_eq!(last.span.bytes().end, text.len());
+
+        // NOTE: text is held back from the last word of letters following another one
+        let mut tokenizer = StreamTokenizer::new();
+        let early: Vec<_> = tokenizer.push(text.as_bytes()).unwrap().collect();
+        assert_eq!(early.last().unwrap().token.as_ref(), " ");
+        assert_eq!(tokenizer.text, "at 10:30.\n");
+    }
+
+    #[cfg(feature = "mandarin")]
+    #[test]
+    fn stream_language() {
+        let text = "我们是学生。他们在工作。";
+        let tokens: Vec<_> = Tokenizer::new(Language::Cmn)
+            .stream(Chunks {
+                bytes: text.as_bytes(),
+                len: 2,
+            })
+            .map(|token| token.unwrap().token.as_ref().to_owned())
+            .collect();
+        assert_eq!(
+            tokens,
+            ["我们", "是", "学生", "。", "他们", "在", "工作", "。"]
+        );
+    }
+
+    #[test]
+    fn stream_long_text() {
+        let text = "1,\u{3000}".repeat(100);
+        let mut tokenizer = StreamTokenizer::new();
+        tokenizer.max_text_len = 16;
+        let mut tokens: Vec<_> = tokenizer.push(text.as_bytes()).unwrap().collect();
+        assert!(!tokens.is_empty());
+        assert!(tokenizer.text.len() <= 16);
+        tokens.extend(tokenizer.finish().unwrap());
+        assert_eq!(tokens, whole(&text));
+
+        // NOTE: text without spaces is cut between tokens
+        let text = "ab.".repeat(100);
+        let mut tokenizer = StreamTokenizer::new();
+        tokenizer.max_text_len = 16;
+        let mut tokens = Vec::new();
+        for chunk in text.as_bytes().chunks(10) {
+            tokens.extend(tokenizer.push(chunk).unwrap());
+            // NOTE: the last token is held back, and may have just grown past the limit
+            assert!(tokenizer.text.len() <= 16 + chunk.len());
+        }
+        tokens.extend(tokenizer.finish().unwrap());
+        let texts: String = tokens.iter().map(|token| token.token.as_ref()).collect();
+        assert_eq!(texts, text);
+        assert_eq!(tokens.last().unwrap().span.bytes().end, text.len());
+    }
+
+    #[test]
+    fn reject_invalid_utf8() {
+        let mut tokenizer = StreamTokenizer::new();
+        assert_eq!(tokenizer.push(b"ok \xe2\x82").unwrap().count(), 0);
+        assert_eq!(
+            tokenizer.push(b"\xac!").unwrap().count(),
+            0,
+            "the sentence may go on"
+        );
+        let tokens: Vec<_> = tokenizer.finish().unwrap().collect();
+        assert_eq!(tokens, whole("ok €!"));
+
+        let mut tokenizer = StreamTokenizer::new();
+        let error = tokenizer.push(b"ok \xff").unwrap_err();
+        assert_eq!(error.kind(), io::ErrorKind::InvalidData);
+
+        let mut tokenizer = StreamTokenizer::new();
+        tokenizer.push(b"ok \xe2\x82").unwrap();
+        let error = tokenizer.finish().unwrap_err();
+        assert_eq!(error.kind(), io::ErrorKind::InvalidData);
+    }
+}
@@ -3,36 +3,100 @@ use std::collections::BTreeSet;
 use itertools::Position;
 use unicase::UniCase;
 
-use crate::{token_positions, Language, Token};
+#[cfg(feature = "temporal")]
+use crate::Temporal;
+use crate::{Language, Punkt, Sentence, Token, Tokenizer};
 
 pub struct Tagger {
     pub lang: Language,
     stop_words: BTreeSet<UniCase<String>>,
+    punkt: Punkt,
+    tokenizer: Tokenizer,
 }
 
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum Tag {
     StopWord,
+    /// A known abbreviation followed by a period, such as "Dr" in "Dr. Smith".
+    Abbreviation,
 }
 
 impl Tagger {
     #[must_use]
     pub fn new(lang: Language) -> Self {
         let stop_words = crate::stop_words::get(lang);
-        Self { lang, stop_words }
+        Self {
+            lang,
+            stop_words,
+            punkt: Punkt::for_language(lang),
+            tokenizer: Tokenizer::new(lang),
+        }
+    }
+
+    /// Learn abbreviations and sentence starters from `corpus`, a plain text in the tagger's language.
+    ///
+    /// See [`Punkt::train`].
+    #[inline]
+    pub fn train(&mut self, corpus: &str) -> &mut Self {
+        self.punkt.train(corpus);
+        self
+    }
+
+    /// Split `text` into sentences, knowing the abbreviations of the tagger's language.
+    #[inline]
+    pub fn sentences<'tagger, 'text: 'tagger>(
+        &'tagger self,
+        text: &'text str,
+    ) -> impl Iterator<Item = Sentence<'text>> + 'tagger {
+        self.punkt.sentences(text)
+    }
+
+    /// Find the date and time expressions in `text`, as "tomorrow at 5pm" or "3 de março",
+    /// resolving relative ones against `reference`.
+    ///
+    /// See [`Temporal`].
+    #[cfg(feature = "temporal")]
+    #[inline]
+    pub fn temporals<'text>(
+        &self,
+        text: &'text str,
+        reference: chrono::NaiveDateTime,
+    ) -> impl Iterator<Item = Temporal<'text>> {
+        crate::temporal::find(self.lang, text, self.tag(text), reference).into_iter()
     }
 
     pub fn tag<'tagger, 'text: 'tagger>(
         &'tagger self,
         text: &'text str,
     ) -> impl Iterator<Item = (Position<usize>, Token<'text>, Option<Tag>)> + 'tagger {
-        token_positions(text).map(|(position, token)| (position, token, self.tag_token(token)))
+        let mut tokens = self
+            .tokenizer
+            .punkt_token_positions(&self.punkt, text)
+            .peekable();
+        std::iter::from_fn(move || {
+            let (position, token) = tokens.next()?;
+            let period = tokens
+                .peek()
+                .is_some_and(|(_, next)| next.as_ref().starts_with('.'));
+            Some((position, token, self.tag_token(token, period)))
+        })
     }
 
-    fn tag_token(&self, token: Token) -> Option<Tag> {
+    #[inline]
+    fn is_stop_word(&self, word: &str) -> bool {
+        !word.is_empty() && self.stop_words.contains(&UniCase::new(word.into()))
+    }
+
+    fn tag_token(&self, token: Token, period: bool) -> Option<Tag> {
         let s = token.as_ref();
-        // TODO: detect abbreviations and/or acronyms first?
-        if self.stop_words.contains(&UniCase::new(s.into())) {
+        // NOTE: abbreviations come first, as some of them are also stop words
+        if period && self.punkt.is_abbreviation(s) {
+            Some(Tag::Abbreviation)
+        } else if self.is_stop_word(s)
+            // NOTE: elisions such as "l'" are listed without their apostrophe
+            || self.is_stop_word(s.trim_end_matches(crate::tokenizer::APOSTROPHES))
+        {
             Some(Tag::StopWord)
         } else {
             None
@@ -77,4 +141,86 @@ mod tests {
             ]
         );
     }
+
+    #[cfg(feature = "english")]
+    #[test]
+    fn tag_abbreviations() {
+        use Position::{First, Last, Middle};
+        use Token::{Letter, Punctuation, Separator};
+
+        let tagger = Tagger::new(Language::Eng);
+        let words: Vec<_> = tagger.tag("Meet Dr. Who, not the dr").collect();
+        assert_eq!(
+            &words[..4],
+            &[
+                (First(0), Letter("Meet"), None),
+                (Middle(4), Separator(" "), None),
+                (Middle(5), Letter("Dr"), Some(Tag::Abbreviation)),
+                (Middle(7), Punctuation("."), None),
+            ]
+        );
+        assert_eq!(words.last(), Some(&(Last(22), Letter("dr"), None)));
+
+        let sentences: Vec<_> = tagger
+            .sentences("See Fig. Two. Then stop.")
+            .map(|sentence| sentence.text)
+            .collect();
+        assert_eq!(sentences, ["See Fig. Two. ", "Then stop."]);
+    }
+
+    #[cfg(all(feature = "english", feature = "spanish"))]
+    #[test]
+    fn sentence_positions() {
+        let ends = |lang, text| -> Vec<_> {
+            Tagger::new(lang)
+                .tag(text)
+                .filter_map(|(position, token, _)| match position {
+                    Position::Last(_) => Some(token.as_ref().to_owned()),
+                    _ => None,
+                })
+                .collect()
+        };
+        assert_eq!(ends(Language::Spa, "Así es. Gracias por todo."), [".", "."]);
+        assert_eq!(
+            ends(Language::Eng, "I saw plan B. Then we left."),
+            [".", "."]
+        );
+        // NOTE: the tagger's own model is trained
+        let mut tagger = Tagger::new(Language::Eng);
+        tagger.train("See Tab. 3 here. See Tab. 4 there.");
+        let ends: Vec<_> = tagger
+            .tag("See Tab. Four.")
+            .filter(|(position, _, _)| matches!(position, Position::Last(_)))
+            .collect();
+        assert_eq!(ends.len(), 1);
+    }
+
+    #[cfg(feature = "mandarin")]
+    #[test]
+    fn tag_mandarin_stop_words() {
+        let tagger = Tagger::new(Language::Cmn);
+        let stop_words: Vec<_> = tagger
+            .tag("我们是学生")
+            .filter(|&(_, _, tag)| tag == Some(Tag::StopWord))
+            .map(|(_, token, _)| token.as_ref().to_owned())
+            .collect();
+        assert_eq!(stop_words, ["我们", "是"]);
+    }
+
+    #[cfg(feature = "french")]
+    #[test]
+    fn tag_elisions() {
+        let tagger = Tagger::new(Language::Fra);
+        let tags: Vec<_> = tagger
+            .tag("l'homme")
+            .map(|(_, token, tag)| (token.as_ref().to_owned(), tag))
+            .collect();
+        assert_eq!(
+            tags,
+            [
+                ("l'".to_owned(), Some(Tag::StopWord)),
+                ("homme".to_owned(), None)
+            ]
+        );
+    }
 }
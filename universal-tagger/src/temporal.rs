@@ -0,0 +1,1568 @@
+use std::ops::Range;
+
+use chrono::{Datelike, Months, NaiveDate, NaiveDateTime, NaiveTime, TimeDelta};
+use itertools::Position;
+
+use crate::{span::Spanner, Language, Span, Tag, Token};
+
+type Words = &'static [&'static str];
+
+/// A unit of duration, as in "in 3 days".
+// NOTE: only the lexicons of enabled languages name units
+#[allow(dead_code)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum Unit {
+    Minute,
+    Hour,
+    Day,
+    Week,
+    Month,
+    Year,
+}
+
+/// Words used in date and time expressions of a language, lowercase.
+struct Lexicon {
+    /// Days relative to the reference day, as "tomorrow".
+    days: &'static [(&'static str, i64)],
+    /// Names of the days of the week, from Monday.
+    weekdays: [Words; 7],
+    /// Names of the months, from January.
+    months: [Words; 12],
+    /// Whether numeric dates put the month first, as in "10/17/2026".
+    month_first: bool,
+    /// Words picking the next, the last or this occurrence of a weekday, before or after it.
+    next: Words,
+    last: Words,
+    this: Words,
+    /// Words between a day, its month and its year, as "de" in "3 de março de 2026".
+    of: Words,
+    /// Markers after the year, month and day numbers, as in "2026年10月17日".
+    markers: [Words; 3],
+    /// Words introducing a time, as "at".
+    at: Words,
+    /// Words after an hour, as "o'clock", "Uhr" or "h".
+    clock: Words,
+    /// Words for hours before and after noon, before or after them.
+    am: Words,
+    pm: Words,
+    /// Times of the day, as "noon", with their hour.
+    hours: &'static [(&'static str, u32)],
+    /// Words before or after a duration counted forwards from the reference, as "in".
+    later: (Words, Words),
+    /// Words before or after a duration counted backwards from the reference, as "ago".
+    earlier: (Words, Words),
+    units: &'static [(&'static str, Unit)],
+    /// Words read only when capitalized, as English "May", lest "may" be taken for a month.
+    capitalized: Words,
+    /// Words read only when lowercase, as German "morgen", lest the noun "Morgen" be taken for tomorrow.
+    lowercase: Words,
+}
+
+const EMPTY: Lexicon = Lexicon {
+    days: &[],
+    weekdays: [&[]; 7],
+    months: [&[]; 12],
+    month_first: false,
+    next: &[],
+    last: &[],
+    this: &[],
+    of: &[],
+    markers: [&[]; 3],
+    at: &[],
+    clock: &[],
+    am: &[],
+    pm: &[],
+    hours: &[],
+    later: (&[], &[]),
+    earlier: (&[], &[]),
+    units: &[],
+    capitalized: &[],
+    lowercase: &[],
+};
+
+/// The words of date and time expressions in `lang`.
+#[allow(clippy::too_many_lines)]
+const fn lexicon(lang: Language) -> &'static Lexicon {
+    #[allow(unused_imports)]
+    use Unit::{Day, Hour, Minute, Month, Week, Year};
+
+    match lang {
+        #[cfg(feature = "arabic")]
+        Language::Ara => &Lexicon {
+            days: &[
+                ("اليوم", 0),
+                ("غدا", 1),
+                ("غداً", 1),
+                ("أمس", -1),
+                ("البارحة", -1),
+                ("بعد غد", 2),
+            ],
+            weekdays: [
+                &["الاثنين", "الإثنين"],
+                &["الثلاثاء"],
+                &["الأربعاء"],
+                &["الخميس"],
+                &["الجمعة"],
+                &["السبت"],
+                &["الأحد"],
+            ],
+            months: [
+                &["يناير", "كانون الثاني"],
+                &["فبراير", "شباط"],
+                &["مارس", "آذار"],
+                &["أبريل", "إبريل", "نيسان"],
+                &["مايو", "أيار"],
+                &["يونيو", "يونيه", "حزيران"],
+                &["يوليو", "يوليه", "تموز"],
+                &["أغسطس", "آب"],
+                &["سبتمبر", "أيلول"],
+                &["أكتوبر", "تشرين الأول"],
+                &["نوفمبر", "تشرين الثاني"],
+                &["ديسمبر", "كانون الأول"],
+            ],
+            next: &["القادم", "المقبل"],
+            last: &["الماضي"],
+            at: &["الساعة"],
+            later: (&["بعد", "خلال"], &[]),
+            earlier: (&["منذ", "قبل"], &[]),
+            units: &[
+                ("دقيقة", Minute),
+                ("دقائق", Minute),
+                ("ساعة", Hour),
+                ("ساعات", Hour),
+                ("يوم", Day),
+                ("أيام", Day),
+                ("أسبوع", Week),
+                ("أسابيع", Week),
+                ("شهر", Month),
+                ("أشهر", Month),
+                ("شهور", Month),
+                ("سنة", Year),
+                ("سنوات", Year),
+                ("عام", Year),
+                ("أعوام", Year),
+            ],
+            ..EMPTY
+        },
+        #[cfg(feature = "bengali")]
+        Language::Ben => &Lexicon {
+            // NOTE: "পরশু" is either two days before or after, so it is left out
+            days: &[("আজ", 0), ("আগামীকাল", 1), ("গতকাল", -1)],
+            weekdays: [
+                &["সোমবার"],
+                &["মঙ্গলবার"],
+                &["বুধবার"],
+                &["বৃহস্পতিবার"],
+                &["শুক্রবার"],
+                &["শনিবার"],
+                &["রবিবার"],
+            ],
+            months: [
+                &["জানুয়ারি"],
+                &["ফেব্রুয়ারি"],
+                &["মার্চ"],
+                &["এপ্রিল"],
+                &["মে"],
+                &["জুন"],
+                &["জুলাই"],
+                &["আগস্ট"],
+                &["সেপ্টেম্বর"],
+                &["অক্টোবর"],
+                &["নভেম্বর"],
+                &["ডিসেম্বর"],
+            ],
+            next: &["আগামী"],
+            last: &["গত"],
+            later: (&[], &["পরে"]),
+            earlier: (&[], &["আগে"]),
+            units: &[
+                ("মিনিট", Minute),
+                ("ঘণ্টা", Hour),
+                ("দিন", Day),
+                ("সপ্তাহ", Week),
+                ("মাস", Month),
+                ("বছর", Year),
+            ],
+            ..EMPTY
+        },
+        #[cfg(feature = "mandarin")]
+        Language::Cmn => &Lexicon {
+            days: &[
+                ("今天", 0),
+                ("明天", 1),
+                ("昨天", -1),
+                ("后天", 2),
+                ("前天", -2),
+            ],
+            weekdays: [
+                &["星期一", "周一", "礼拜一"],
+                &["星期二", "周二", "礼拜二"],
+                &["星期三", "周三", "礼拜三"],
+                &["星期四", "周四", "礼拜四"],
+                &["星期五", "周五", "礼拜五"],
+                &["星期六", "周六", "礼拜六"],
+                &["星期日", "星期天", "周日", "礼拜天"],
+            ],
+            next: &["下"],
+            last: &["上"],
+            this: &["这", "本"],
+            markers: [&["年"], &["月"], &["日", "号"]],
+            clock: &["点", "点钟"],
+            am: &["上午", "早上"],
+            pm: &["下午", "晚上"],
+            hours: &[("中午", 12), ("午夜", 0)],
+            later: (&[], &["后", "以后"]),
+            earlier: (&[], &["前", "以前"]),
+            units: &[
+                ("分钟", Minute),
+                ("小时", Hour),
+                ("天", Day),
+                ("周", Week),
+                ("星期", Week),
+                ("个星期", Week),
+                ("个月", Month),
+                ("年", Year),
+            ],
+            ..EMPTY
+        },
+        #[cfg(feature = "german")]
+        Language::Deu => &Lexicon {
+            days: &[
+                ("heute", 0),
+                ("morgen", 1),
+                ("gestern", -1),
+                ("übermorgen", 2),
+                ("vorgestern", -2),
+            ],
+            weekdays: [
+                &["montag"],
+                &["dienstag"],
+                &["mittwoch"],
+                &["donnerstag"],
+                &["freitag"],
+                &["samstag", "sonnabend"],
+                &["sonntag"],
+            ],
+            months: [
+                &["januar", "jänner", "jan"],
+                &["februar", "feb"],
+                &["märz", "mär"],
+                &["april", "apr"],
+                &["mai"],
+                &["juni", "jun"],
+                &["juli", "jul"],
+                &["august", "aug"],
+                &["september", "sep", "sept"],
+                &["oktober", "okt"],
+                &["november", "nov"],
+                &["dezember", "dez"],
+            ],
+            next: &["nächsten", "nächster", "nächste", "kommenden", "kommender"],
+            last: &["letzten", "letzter", "letzte", "vergangenen"],
+            this: &["diesen", "dieser", "am"],
+            of: &["."],
+            at: &["um"],
+            clock: &["uhr"],
+            hours: &[("mittag", 12), ("mitternacht", 0)],
+            later: (&["in"], &[]),
+            earlier: (&["vor"], &[]),
+            units: &[
+                ("minute", Minute),
+                ("minuten", Minute),
+                ("stunde", Hour),
+                ("stunden", Hour),
+                ("tag", Day),
+                ("tage", Day),
+                ("tagen", Day),
+                ("woche", Week),
+                ("wochen", Week),
+                ("monat", Month),
+                ("monate", Month),
+                ("monaten", Month),
+                ("jahr", Year),
+                ("jahre", Year),
+                ("jahren", Year),
+            ],
+            lowercase: &["morgen"],
+            ..EMPTY
+        },
+        #[cfg(feature = "english")]
+        Language::Eng => &Lexicon {
+            days: &[
+                ("today", 0),
+                ("tonight", 0),
+                ("tomorrow", 1),
+                ("yesterday", -1),
+                ("the day after tomorrow", 2),
+                ("the day before yesterday", -2),
+            ],
+            weekdays: [
+                &["monday"],
+                &["tuesday"],
+                &["wednesday"],
+                &["thursday"],
+                &["friday"],
+                &["saturday"],
+                &["sunday"],
+            ],
+            months: [
+                &["january", "jan"],
+                &["february", "feb"],
+                &["march", "mar"],
+                &["april", "apr"],
+                &["may"],
+                &["june", "jun"],
+                &["july", "jul"],
+                &["august", "aug"],
+                &["september", "sep", "sept"],
+                &["october", "oct"],
+                &["november", "nov"],
+                &["december", "dec"],
+            ],
+            month_first: true,
+            next: &["next", "coming"],
+            last: &["last", "past"],
+            this: &["this", "on"],
+            of: &["of", ","],
+            at: &["at"],
+            clock: &["o'clock", "o’clock"],
+            am: &["am", "a.m."],
+            pm: &["pm", "p.m."],
+            hours: &[("noon", 12), ("midnight", 0)],
+            later: (&["in"], &["from now"]),
+            earlier: (&[], &["ago"]),
+            units: &[
+                ("minute", Minute),
+                ("minutes", Minute),
+                ("hour", Hour),
+                ("hours", Hour),
+                ("day", Day),
+                ("days", Day),
+                ("week", Week),
+                ("weeks", Week),
+                ("month", Month),
+                ("months", Month),
+                ("year", Year),
+                ("years", Year),
+            ],
+            capitalized: &["march", "mar", "may", "august"],
+            ..EMPTY
+        },
+        #[cfg(feature = "esperanto")]
+        Language::Epo => &Lexicon {
+            days: &[
+                ("hodiaŭ", 0),
+                ("morgaŭ", 1),
+                ("hieraŭ", -1),
+                ("postmorgaŭ", 2),
+                ("antaŭhieraŭ", -2),
+            ],
+            weekdays: [
+                &["lundo", "lundon"],
+                &["mardo", "mardon"],
+                &["merkredo", "merkredon"],
+                &["ĵaŭdo", "ĵaŭdon"],
+                &["vendredo", "vendredon"],
+                &["sabato", "sabaton"],
+                &["dimanĉo", "dimanĉon"],
+            ],
+            months: [
+                &["januaro"],
+                &["februaro"],
+                &["marto"],
+                &["aprilo"],
+                &["majo"],
+                &["junio"],
+                &["julio"],
+                &["aŭgusto"],
+                &["septembro"],
+                &["oktobro"],
+                &["novembro"],
+                &["decembro"],
+            ],
+            next: &["venonta", "venontan"],
+            last: &["pasinta", "pasintan", "lasta", "lastan"],
+            of: &["de"],
+            at: &["je"],
+            later: (&["post"], &[]),
+            earlier: (&["antaŭ"], &[]),
+            units: &[
+                ("minuto", Minute),
+                ("minutoj", Minute),
+                ("horo", Hour),
+                ("horoj", Hour),
+                ("tago", Day),
+                ("tagoj", Day),
+                ("semajno", Week),
+                ("semajnoj", Week),
+                ("monato", Month),
+                ("monatoj", Month),
+                ("jaro", Year),
+                ("jaroj", Year),
+            ],
+            ..EMPTY
+        },
+        #[cfg(feature = "french")]
+        Language::Fra => &Lexicon {
+            days: &[
+                ("aujourd'hui", 0),
+                ("aujourd’hui", 0),
+                ("demain", 1),
+                ("hier", -1),
+                ("après-demain", 2),
+                ("avant-hier", -2),
+            ],
+            weekdays: [
+                &["lundi"],
+                &["mardi"],
+                &["mercredi"],
+                &["jeudi"],
+                &["vendredi"],
+                &["samedi"],
+                &["dimanche"],
+            ],
+            months: [
+                &["janvier", "janv"],
+                &["février", "févr"],
+                &["mars"],
+                &["avril", "avr"],
+                &["mai"],
+                &["juin"],
+                &["juillet", "juil"],
+                &["août"],
+                &["septembre", "sept"],
+                &["octobre", "oct"],
+                &["novembre", "nov"],
+                &["décembre", "déc"],
+            ],
+            next: &["prochain", "prochaine"],
+            last: &["dernier", "dernière"],
+            this: &["ce"],
+            at: &["à"],
+            clock: &["h", "heures", "heure"],
+            hours: &[("midi", 12), ("minuit", 0)],
+            later: (&["dans"], &[]),
+            earlier: (&["il y a"], &[]),
+            units: &[
+                ("minute", Minute),
+                ("minutes", Minute),
+                ("heure", Hour),
+                ("heures", Hour),
+                ("jour", Day),
+                ("jours", Day),
+                ("semaine", Week),
+                ("semaines", Week),
+                ("mois", Month),
+                ("an", Year),
+                ("ans", Year),
+                ("année", Year),
+                ("années", Year),
+            ],
+            ..EMPTY
+        },
+        #[cfg(feature = "hindi")]
+        Language::Hin => &Lexicon {
+            // NOTE: "कल" and "परसों" go either way in time, so they are left out
+            days: &[("आज", 0)],
+            weekdays: [
+                &["सोमवार"],
+                &["मंगलवार"],
+                &["बुधवार"],
+                &["गुरुवार", "बृहस्पतिवार"],
+                &["शुक्रवार"],
+                &["शनिवार"],
+                &["रविवार"],
+            ],
+            months: [
+                &["जनवरी"],
+                &["फ़रवरी", "फरवरी"],
+                &["मार्च"],
+                &["अप्रैल"],
+                &["मई"],
+                &["जून"],
+                &["जुलाई"],
+                &["अगस्त"],
+                &["सितंबर", "सितम्बर"],
+                &["अक्टूबर", "अक्तूबर"],
+                &["नवंबर", "नवम्बर"],
+                &["दिसंबर", "दिसम्बर"],
+            ],
+            next: &["अगले", "अगला"],
+            last: &["पिछले", "पिछला"],
+            clock: &["बजे"],
+            later: (&[], &["बाद"]),
+            earlier: (&[], &["पहले"]),
+            units: &[
+                ("मिनट", Minute),
+                ("घंटे", Hour),
+                ("घंटा", Hour),
+                ("दिन", Day),
+                ("हफ़्ते", Week),
+                ("हफ्ते", Week),
+                ("सप्ताह", Week),
+                ("महीने", Month),
+                ("महीना", Month),
+                ("साल", Year),
+                ("वर्ष", Year),
+            ],
+            ..EMPTY
+        },
+        #[cfg(feature = "indonesian")]
+        Language::Ind => &Lexicon {
+            days: &[("hari ini", 0), ("besok", 1), ("kemarin", -1), ("lusa", 2)],
+            weekdays: [
+                &["senin"],
+                &["selasa"],
+                &["rabu"],
+                &["kamis"],
+                &["jumat", "jum'at"],
+                &["sabtu"],
+                &["minggu"],
+            ],
+            months: [
+                &["januari"],
+                &["februari"],
+                &["maret"],
+                &["april"],
+                &["mei"],
+                &["juni"],
+                &["juli"],
+                &["agustus"],
+                &["september"],
+                &["oktober"],
+                &["november"],
+                &["desember"],
+            ],
+            next: &["depan", "berikutnya"],
+            last: &["lalu"],
+            this: &["ini"],
+            at: &["pukul", "jam"],
+            later: (&["dalam"], &["lagi"]),
+            earlier: (&[], &["lalu", "yang lalu"]),
+            units: &[
+                ("menit", Minute),
+                ("jam", Hour),
+                ("hari", Day),
+                ("minggu", Week),
+                ("pekan", Week),
+                ("bulan", Month),
+                ("tahun", Year),
+            ],
+            ..EMPTY
+        },
+        #[cfg(feature = "italian")]
+        Language::Ita => &Lexicon {
+            days: &[
+                ("oggi", 0),
+                ("domani", 1),
+                ("ieri", -1),
+                ("dopodomani", 2),
+                ("altroieri", -2),
+                ("l'altro ieri", -2),
+            ],
+            weekdays: [
+                &["lunedì"],
+                &["martedì"],
+                &["mercoledì"],
+                &["giovedì"],
+                &["venerdì"],
+                &["sabato"],
+                &["domenica"],
+            ],
+            months: [
+                &["gennaio"],
+                &["febbraio"],
+                &["marzo"],
+                &["aprile"],
+                &["maggio"],
+                &["giugno"],
+                &["luglio"],
+                &["agosto"],
+                &["settembre"],
+                &["ottobre"],
+                &["novembre"],
+                &["dicembre"],
+            ],
+            next: &["prossimo", "prossima"],
+            last: &["scorso", "scorsa"],
+            this: &["questo", "questa"],
+            at: &["alle", "all'", "all’"],
+            hours: &[("mezzogiorno", 12), ("mezzanotte", 0)],
+            later: (&["tra", "fra"], &[]),
+            earlier: (&[], &["fa"]),
+            units: &[
+                ("minuto", Minute),
+                ("minuti", Minute),
+                ("ora", Hour),
+                ("ore", Hour),
+                ("giorno", Day),
+                ("giorni", Day),
+                ("settimana", Week),
+                ("settimane", Week),
+                ("mese", Month),
+                ("mesi", Month),
+                ("anno", Year),
+                ("anni", Year),
+            ],
+            ..EMPTY
+        },
+        #[cfg(feature = "japanese")]
+        Language::Jpn => &Lexicon {
+            days: &[
+                ("今日", 0),
+                ("明日", 1),
+                ("昨日", -1),
+                ("明後日", 2),
+                ("一昨日", -2),
+            ],
+            weekdays: [
+                &["月曜日", "月曜"],
+                &["火曜日", "火曜"],
+                &["水曜日", "水曜"],
+                &["木曜日", "木曜"],
+                &["金曜日", "金曜"],
+                &["土曜日", "土曜"],
+                &["日曜日", "日曜"],
+            ],
+            next: &["来週の", "次の"],
+            last: &["先週の"],
+            this: &["今週の"],
+            markers: [&["年"], &["月"], &["日"]],
+            clock: &["時"],
+            am: &["午前"],
+            pm: &["午後"],
+            hours: &[("正午", 12)],
+            later: (&[], &["後"]),
+            earlier: (&[], &["前"]),
+            units: &[
+                ("分", Minute),
+                ("時間", Hour),
+                ("日", Day),
+                ("週間", Week),
+                ("ヶ月", Month),
+                ("か月", Month),
+                ("年", Year),
+            ],
+            ..EMPTY
+        },
+        #[cfg(feature = "portuguese")]
+        Language::Por => &Lexicon {
+            days: &[
+                ("hoje", 0),
+                ("amanhã", 1),
+                ("ontem", -1),
+                ("depois de amanhã", 2),
+                ("anteontem", -2),
+            ],
+            weekdays: [
+                &["segunda-feira", "segunda"],
+                &["terça-feira", "terça"],
+                &["quarta-feira", "quarta"],
+                &["quinta-feira", "quinta"],
+                &["sexta-feira", "sexta"],
+                &["sábado"],
+                &["domingo"],
+            ],
+            months: [
+                &["janeiro", "jan"],
+                &["fevereiro", "fev"],
+                &["março", "mar"],
+                &["abril", "abr"],
+                &["maio", "mai"],
+                &["junho", "jun"],
+                &["julho", "jul"],
+                &["agosto", "ago"],
+                &["setembro", "set"],
+                &["outubro", "out"],
+                &["novembro", "nov"],
+                &["dezembro", "dez"],
+            ],
+            next: &["próxima", "próximo", "que vem"],
+            last: &["passada", "passado", "última", "último"],
+            this: &["esta", "este", "nesta", "neste"],
+            of: &["de"],
+            at: &["às", "as", "à"],
+            clock: &["h", "horas"],
+            hours: &[("meio-dia", 12), ("meia-noite", 0)],
+            later: (&["em", "daqui a", "dentro de"], &[]),
+            earlier: (&["há", "faz"], &["atrás"]),
+            units: &[
+                ("minuto", Minute),
+                ("minutos", Minute),
+                ("hora", Hour),
+                ("horas", Hour),
+                ("dia", Day),
+                ("dias", Day),
+                ("semana", Week),
+                ("semanas", Week),
+                ("mês", Month),
+                ("meses", Month),
+                ("ano", Year),
+                ("anos", Year),
+            ],
+            ..EMPTY
+        },
+        #[cfg(feature = "russian")]
+        Language::Rus => &Lexicon {
+            days: &[
+                ("сегодня", 0),
+                ("завтра", 1),
+                ("вчера", -1),
+                ("послезавтра", 2),
+                ("позавчера", -2),
+            ],
+            weekdays: [
+                &["понедельник"],
+                &["вторник"],
+                &["среда", "среду"],
+                &["четверг"],
+                &["пятница", "пятницу"],
+                &["суббота", "субботу"],
+                &["воскресенье"],
+            ],
+            months: [
+                &["января", "январь"],
+                &["февраля", "февраль"],
+                &["марта", "март"],
+                &["апреля", "апрель"],
+                &["мая", "май"],
+                &["июня", "июнь"],
+                &["июля", "июль"],
+                &["августа", "август"],
+                &["сентября", "сентябрь"],
+                &["октября", "октябрь"],
+                &["ноября", "ноябрь"],
+                &["декабря", "декабрь"],
+            ],
+            next: &["следующий", "следующую", "следующее", "следующей"],
+            last: &["прошлый", "прошлую", "прошлое", "прошлой"],
+            this: &["этот", "эту", "это"],
+            at: &["в"],
+            hours: &[("полдень", 12), ("полночь", 0)],
+            later: (&["через"], &[]),
+            earlier: (&[], &["назад"]),
+            units: &[
+                ("минуту", Minute),
+                ("минуты", Minute),
+                ("минут", Minute),
+                ("час", Hour),
+                ("часа", Hour),
+                ("часов", Hour),
+                ("день", Day),
+                ("дня", Day),
+                ("дней", Day),
+                ("неделю", Week),
+                ("недели", Week),
+                ("недель", Week),
+                ("месяц", Month),
+                ("месяца", Month),
+                ("месяцев", Month),
+                ("год", Year),
+                ("года", Year),
+                ("лет", Year),
+            ],
+            ..EMPTY
+        },
+        #[cfg(feature = "spanish")]
+        Language::Spa => &Lexicon {
+            days: &[
+                ("hoy", 0),
+                ("mañana", 1),
+                ("ayer", -1),
+                ("pasado mañana", 2),
+                ("anteayer", -2),
+            ],
+            weekdays: [
+                &["lunes"],
+                &["martes"],
+                &["miércoles"],
+                &["jueves"],
+                &["viernes"],
+                &["sábado"],
+                &["domingo"],
+            ],
+            months: [
+                &["enero", "ene"],
+                &["febrero", "feb"],
+                &["marzo", "mar"],
+                &["abril", "abr"],
+                &["mayo", "may"],
+                &["junio", "jun"],
+                &["julio", "jul"],
+                &["agosto", "ago"],
+                &["septiembre", "setiembre", "sept"],
+                &["octubre", "oct"],
+                &["noviembre", "nov"],
+                &["diciembre", "dic"],
+            ],
+            next: &["próximo", "próxima", "que viene"],
+            last: &["pasado", "pasada", "último", "última"],
+            this: &["este", "esta"],
+            of: &["de"],
+            at: &["a las", "a la"],
+            clock: &["h"],
+            hours: &[("mediodía", 12), ("medianoche", 0)],
+            later: (&["en", "dentro de"], &[]),
+            earlier: (&["hace"], &[]),
+            units: &[
+                ("minuto", Minute),
+                ("minutos", Minute),
+                ("hora", Hour),
+                ("horas", Hour),
+                ("día", Day),
+                ("días", Day),
+                ("semana", Week),
+                ("semanas", Week),
+                ("mes", Month),
+                ("meses", Month),
+                ("año", Year),
+                ("años", Year),
+            ],
+            ..EMPTY
+        },
+        #[cfg(feature = "turkish")]
+        Language::Tur => &Lexicon {
+            days: &[("bugün", 0), ("yarın", 1), ("dün", -1)],
+            weekdays: [
+                &["pazartesi"],
+                &["salı"],
+                &["çarşamba"],
+                &["perşembe"],
+                &["cuma"],
+                &["cumartesi"],
+                &["pazar"],
+            ],
+            months: [
+                &["ocak"],
+                &["şubat"],
+                &["mart"],
+                &["nisan"],
+                &["mayıs"],
+                &["haziran"],
+                &["temmuz"],
+                &["ağustos"],
+                &["eylül"],
+                &["ekim"],
+                &["kasım"],
+                &["aralık"],
+            ],
+            next: &["gelecek", "önümüzdeki"],
+            last: &["geçen"],
+            this: &["bu"],
+            at: &["saat"],
+            later: (&[], &["sonra"]),
+            earlier: (&[], &["önce"]),
+            units: &[
+                ("dakika", Minute),
+                ("saat", Hour),
+                ("gün", Day),
+                ("hafta", Week),
+                ("ay", Month),
+                ("yıl", Year),
+            ],
+            ..EMPTY
+        },
+        #[cfg(feature = "urdu")]
+        Language::Urd => &Lexicon {
+            // NOTE: "کل" goes either way in time, so it is left out
+            days: &[("آج", 0)],
+            weekdays: [
+                &["پیر"],
+                &["منگل"],
+                &["بدھ"],
+                &["جمعرات"],
+                &["جمعہ"],
+                &["ہفتہ"],
+                &["اتوار"],
+            ],
+            months: [
+                &["جنوری"],
+                &["فروری"],
+                &["مارچ"],
+                &["اپریل"],
+                &["مئی"],
+                &["جون"],
+                &["جولائی"],
+                &["اگست"],
+                &["ستمبر"],
+                &["اکتوبر"],
+                &["نومبر"],
+                &["دسمبر"],
+            ],
+            next: &["اگلے"],
+            last: &["پچھلے"],
+            clock: &["بجے"],
+            later: (&[], &["بعد"]),
+            earlier: (&[], &["پہلے"]),
+            units: &[
+                ("منٹ", Minute),
+                ("گھنٹے", Hour),
+                ("دن", Day),
+                ("ہفتے", Week),
+                ("مہینے", Month),
+                ("سال", Year),
+            ],
+            ..EMPTY
+        },
+        #[allow(unreachable_patterns)]
+        _ => &EMPTY,
+    }
+}
+
+/// What a date or time expression refers to, resolved against a reference instant.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum TemporalValue {
+    /// A whole day, as "2026-10-17", "tomorrow" or "next Friday".
+    Date(NaiveDate),
+    /// An instant, as "tomorrow at 5pm", "17:30" or "in 2 hours".
+    DateTime(NaiveDateTime),
+}
+
+/// A date or time expression found in a text.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Temporal<'text> {
+    pub text: &'text str,
+    pub span: Span,
+    pub value: TemporalValue,
+}
+
+/// Which occurrence of a weekday is meant.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum Occurrence {
+    Next,
+    Last,
+    This,
+}
+
+/// A word of a tagged text, without the spaces around it.
+struct Word<'text> {
+    range: Range<usize>,
+    token: Token<'text>,
+    tag: Option<Tag>,
+}
+
+/// Reads date and time expressions at the words of a text.
+struct Reader<'text> {
+    text: &'text str,
+    words: Vec<Word<'text>>,
+    /// Byte ranges of numbers spelled out in words, with their values.
+    quantities: Vec<(Range<usize>, u64)>,
+    lexicon: &'static Lexicon,
+    reference: NaiveDateTime,
+}
+
+impl Reader<'_> {
+    /// The index of the word after the one ending at byte `end`, if any word does.
+    fn after(&self, from: usize, end: usize) -> Option<usize> {
+        // NOTE: words are sorted and disjoint, so their ends are sorted as well
+        let words = &self.words[from..];
+        let index = words.partition_point(|word| word.range.end < end);
+        words
+            .get(index)
+            .is_some_and(|word| word.range.end == end)
+            .then_some(from + index + 1)
+    }
+
+    /// Read the longest of `phrases` at word `i`, returning the index of the word after it
+    /// along with the index of the phrase.
+    fn phrase(&self, i: usize, phrases: &[&str]) -> Option<(usize, usize)> {
+        let start = self.words.get(i)?.range.start;
+        phrases
+            .iter()
+            .enumerate()
+            .filter_map(|(index, phrase)| {
+                let len: usize = self.text[start..]
+                    .chars()
+                    .take(phrase.chars().count())
+                    .map(char::len_utf8)
+                    .sum();
+                let found = &self.text[start..start + len];
+                let cased = if found.starts_with(char::is_uppercase) {
+                    !self.lexicon.lowercase.contains(phrase)
+                } else {
+                    !self.lexicon.capitalized.contains(phrase)
+                };
+                (cased && found.to_lowercase() == *phrase)
+                    .then(|| self.after(i, start + len))
+                    .flatten()
+                    .map(|next| (next, index))
+            })
+            .max_by_key(|&(next, _)| next)
+    }
+
+    /// Read any of `phrases` at word `i`, returning the index of the word after it.
+    #[inline]
+    fn any(&self, i: usize, phrases: &[&str]) -> Option<usize> {
+        self.phrase(i, phrases).map(|(next, _)| next)
+    }
+
+    /// Read one of groups of phrases at word `i`, returning the index of the group.
+    fn one_of(&self, i: usize, groups: &[Words]) -> Option<(usize, usize)> {
+        groups
+            .iter()
+            .enumerate()
+            .filter_map(|(index, group)| Some((self.any(i, group)?, index)))
+            .max_by_key(|&(next, _)| next)
+    }
+
+    /// Read a whole number at word `i`, written in digits or in words.
+    fn number(&self, i: usize) -> Option<(usize, u64)> {
+        let word = self.words.get(i)?;
+        if let Token::Number(number) = word.token {
+            let value = number.value?;
+            let whole = value >= 0.0 && value.fract() == 0.0;
+            #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+            return whole.then_some((i + 1, value as u64));
+        }
+        let (range, value) = self
+            .quantities
+            .iter()
+            .find(|(range, _)| range.start == word.range.start)?;
+        Some((self.after(i, range.end)?, *value))
+    }
+
+    /// Read the day of a month at word `i`.
+    fn day(&self, i: usize) -> Option<(usize, u32)> {
+        let (next, day) = self.number(i)?;
+        let day = u32::try_from(day)
+            .ok()
+            .filter(|day| (1..=31).contains(day))?;
+        Some((next, day))
+    }
+
+    /// Read a year written with four digits at word `i`.
+    fn year(&self, i: usize) -> Option<(usize, i32)> {
+        let (next, year) = self.number(i)?;
+        let year = i32::try_from(year)
+            .ok()
+            .filter(|year| (1000..=9999).contains(year))?;
+        Some((next, year))
+    }
+
+    /// Read the name of a month at word `i`, along with a period after an abbreviated one.
+    fn month(&self, i: usize) -> Option<(usize, u32)> {
+        let (mut next, month) = self.one_of(i, &self.lexicon.months)?;
+        let abbreviated = self.words[next - 1].tag == Some(Tag::Abbreviation);
+        if abbreviated
+            && self
+                .words
+                .get(next)
+                .is_some_and(|word| word.token.as_ref() == ".")
+        {
+            next += 1;
+        }
+        Some((next, u32::try_from(month).ok()? + 1))
+    }
+
+    /// Read an optional connector between the parts of a date, as "de" or ",".
+    #[inline]
+    fn of(&self, i: usize) -> usize {
+        self.any(i, self.lexicon.of).unwrap_or(i)
+    }
+
+    /// Complete a day and month with a year read after word `i`, or the year of the reference.
+    fn with_year(&self, i: usize, month: u32, day: u32) -> Option<(usize, NaiveDate)> {
+        let (next, year) = self.year(self.of(i)).unwrap_or((i, self.reference.year()));
+        Some((next, NaiveDate::from_ymd_opt(year, month, day)?))
+    }
+
+    /// Read a date written with a month name, as "3 de março" or "March 3rd, 2026".
+    fn named_date(&self, i: usize) -> Option<(usize, NaiveDate)> {
+        let day_first = self.day(i).and_then(|(next, day)| {
+            let (next, month) = self.month(self.of(next))?;
+            self.with_year(next, month, day)
+        });
+        let month_first = self.month(i).and_then(|(next, month)| {
+            let (next, day) = self.day(next)?;
+            self.with_year(next, month, day)
+        });
+        day_first
+            .into_iter()
+            .chain(month_first)
+            .max_by_key(|&(next, _)| next)
+    }
+
+    /// Read a date marking its parts, as "2026年10月17日".
+    fn marked_date(&self, i: usize) -> Option<(usize, NaiveDate)> {
+        let [years, months, days] = self.lexicon.markers;
+        let (i, year) = self
+            .year(i)
+            .and_then(|(next, year)| Some((self.any(next, years)?, year)))
+            .unwrap_or((i, self.reference.year()));
+        let (next, month) = self.number(i)?;
+        let next = self.any(next, months)?;
+        let (next, day) = self.number(next)?;
+        let next = self.any(next, days)?;
+        let date = NaiveDate::from_ymd_opt(year, month.try_into().ok()?, day.try_into().ok()?)?;
+        Some((next, date))
+    }
+
+    /// Read a date written in digits, as "2026-10-17", "17/10/2026" or "17.10.2026".
+    fn numeric_date(&self, i: usize) -> Option<(usize, NaiveDate)> {
+        let start = self.words.get(i)?.range.start;
+        let rest = &self.text[start..];
+        let separator = rest.chars().find(|c| !c.is_ascii_digit())?;
+        if !matches!(separator, '-' | '/' | '.') {
+            return None;
+        }
+        let mut len = 0;
+        let mut parts = Vec::new();
+        for part in rest.splitn(3, separator) {
+            let digits: usize = part.chars().take_while(char::is_ascii_digit).count();
+            if digits == 0 {
+                return None;
+            }
+            parts.push((&part[..digits], digits));
+            len += digits;
+            if parts.len() < 3 {
+                if digits != part.len() {
+                    return None;
+                }
+                len += 1;
+            }
+        }
+        let [(first, first_len), (second, _), (third, third_len)] = parts[..] else {
+            return None;
+        };
+        let (year, month, day) = match (first_len, third_len) {
+            (4, 1..=2) => (first, second, third),
+            (1..=2, 4) if self.lexicon.month_first => (third, first, second),
+            (1..=2, 4) => (third, second, first),
+            _ => return None,
+        };
+        let date =
+            NaiveDate::from_ymd_opt(year.parse().ok()?, month.parse().ok()?, day.parse().ok()?)?;
+        Some((self.after(i, start + len)?, date))
+    }
+
+    /// Read a weekday, possibly with which occurrence of it is meant, as "next Friday".
+    fn weekday(&self, i: usize) -> Option<(usize, NaiveDate)> {
+        let occurrence = |i| {
+            let lexicon = self.lexicon;
+            [
+                (lexicon.next, Occurrence::Next),
+                (lexicon.last, Occurrence::Last),
+                (lexicon.this, Occurrence::This),
+            ]
+            .into_iter()
+            .find_map(|(words, occurrence)| Some((self.any(i, words)?, occurrence)))
+        };
+        let (next, before) =
+            occurrence(i).map_or((i, None), |(next, occurrence)| (next, Some(occurrence)));
+        let (mut next, weekday) = self.one_of(next, &self.lexicon.weekdays)?;
+        let occurrence = before.or_else(|| {
+            let (after, occurrence) = occurrence(next)?;
+            next = after;
+            Some(occurrence)
+        });
+
+        let today = self.reference.date();
+        let from = i64::from(today.weekday().num_days_from_monday());
+        let weekday = i64::try_from(weekday).ok()?;
+        let days = match occurrence {
+            Some(Occurrence::Last) => -(from - weekday - 1).rem_euclid(7) - 1,
+            Some(Occurrence::Next) => (weekday - from - 1).rem_euclid(7) + 1,
+            Some(Occurrence::This) | None => (weekday - from).rem_euclid(7),
+        };
+        Some((next, today + TimeDelta::days(days)))
+    }
+
+    /// Read a date at word `i`.
+    fn date(&self, i: usize) -> Option<(usize, NaiveDate)> {
+        let today = self.reference.date();
+        let relative = self
+            .lexicon
+            .days
+            .iter()
+            .filter_map(|&(phrase, days)| {
+                Some((self.any(i, &[phrase])?, today + TimeDelta::days(days)))
+            })
+            .max_by_key(|&(next, _)| next);
+        [
+            relative,
+            self.weekday(i),
+            self.numeric_date(i),
+            self.named_date(i),
+            self.marked_date(i),
+        ]
+        .into_iter()
+        .flatten()
+        .max_by_key(|&(next, _)| next)
+    }
+
+    /// Read a time of the day at word `i`, as "5pm", "17:30", "17h" or "noon".
+    fn time(&self, i: usize) -> Option<(usize, NaiveTime)> {
+        let lexicon = self.lexicon;
+        let named = lexicon
+            .hours
+            .iter()
+            .filter_map(|&(phrase, hour)| {
+                Some((
+                    self.any(i, &[phrase])?,
+                    NaiveTime::from_hms_opt(hour, 0, 0)?,
+                ))
+            })
+            .max_by_key(|&(next, _)| next);
+        named.or_else(|| {
+            let half = |i| {
+                self.any(i, lexicon.am)
+                    .map(|next| (next, false))
+                    .or_else(|| self.any(i, lexicon.pm).map(|next| (next, true)))
+            };
+            let (i, before) = half(i).map_or((i, None), |(next, pm)| (next, Some(pm)));
+            let start = self.words.get(i)?.range.start;
+            let rest = &self.text[start..];
+            let digits = |text: &str| text.chars().take_while(char::is_ascii_digit).count();
+
+            let len = digits(rest);
+            if !(1..=2).contains(&len) {
+                return None;
+            }
+            let mut hour: u32 = rest[..len].parse().ok()?;
+            let mut end = len;
+            let mut minute = 0;
+            let colon = rest[end..].starts_with(':') && digits(&rest[end + 1..]) == 2;
+            if colon {
+                minute = rest[end + 1..end + 3].parse().ok()?;
+                end += 3;
+            }
+
+            // NOTE: "5 pm" and "5pm" alike, but "5" alone is not a time
+            let spaces = rest[end..].len() - rest[end..].trim_start().len();
+            let suffix = |phrases: Words| {
+                phrases.iter().find_map(|phrase| {
+                    let len: usize = rest[end + spaces..]
+                        .chars()
+                        .take(phrase.chars().count())
+                        .map(char::len_utf8)
+                        .sum();
+                    (rest[end + spaces..end + spaces + len].to_lowercase() == *phrase)
+                        .then_some(spaces + len)
+                })
+            };
+            let after = suffix(lexicon.am)
+                .map(|len| (len, false))
+                .or_else(|| suffix(lexicon.pm).map(|len| (len, true)));
+            let pm = before.or(after.map(|(_, pm)| pm));
+            if let Some((len, _)) = after {
+                end += len;
+            } else if let Some(len) = suffix(lexicon.clock) {
+                end += len;
+                // NOTE: minutes may follow, as in "17h30"
+                if !colon && digits(&rest[end..]) == 2 {
+                    minute = rest[end..end + 2].parse().ok()?;
+                    end += 2;
+                }
+            } else if !colon && before.is_none() {
+                return None;
+            }
+
+            match pm {
+                Some(true) if hour < 12 => hour += 12,
+                Some(false) if hour == 12 => hour = 0,
+                Some(_) if hour > 12 => return None,
+                _ => {}
+            }
+            Some((
+                self.after(i, start + end)?,
+                NaiveTime::from_hms_opt(hour, minute, 0)?,
+            ))
+        })
+    }
+
+    /// Read a duration counted from the reference instant, as "in 3 days" or "2 hours ago".
+    fn duration(&self, i: usize) -> Option<(usize, TemporalValue)> {
+        let lexicon = self.lexicon;
+        let amount = |i| {
+            let (next, amount) = self.number(i)?;
+            let (next, unit) = lexicon
+                .units
+                .iter()
+                .filter_map(|&(phrase, unit)| Some((self.any(next, &[phrase])?, unit)))
+                .max_by_key(|&(next, _)| next)?;
+            Some((next, amount, unit))
+        };
+        let prefixed = [(lexicon.later.0, true), (lexicon.earlier.0, false)]
+            .into_iter()
+            .find_map(|(phrases, later)| {
+                let (next, amount, unit) = amount(self.any(i, phrases)?)?;
+                Some((next, amount, unit, later))
+            });
+        let suffixed = || {
+            let (next, amount, unit) = amount(i)?;
+            [(lexicon.later.1, true), (lexicon.earlier.1, false)]
+                .into_iter()
+                .find_map(|(phrases, later)| Some((self.any(next, phrases)?, amount, unit, later)))
+        };
+        let (next, amount, unit, later) = prefixed.or_else(suffixed)?;
+
+        let minutes = |minutes: u64| {
+            let delta = TimeDelta::try_minutes(i64::try_from(minutes).ok()?)?;
+            let instant = if later {
+                self.reference.checked_add_signed(delta)
+            } else {
+                self.reference.checked_sub_signed(delta)
+            };
+            instant.map(TemporalValue::DateTime)
+        };
+        let months = |months: u64| {
+            let months = Months::new(u32::try_from(months).ok()?);
+            let today = self.reference.date();
+            let date = if later {
+                today.checked_add_months(months)
+            } else {
+                today.checked_sub_months(months)
+            };
+            date.map(TemporalValue::Date)
+        };
+        let days = |days: u64| {
+            let delta = TimeDelta::try_days(i64::try_from(days).ok()?)?;
+            let today = self.reference.date();
+            let date = if later {
+                today.checked_add_signed(delta)
+            } else {
+                today.checked_sub_signed(delta)
+            };
+            date.map(TemporalValue::Date)
+        };
+        let value = match unit {
+            Unit::Minute => minutes(amount),
+            Unit::Hour => minutes(amount.checked_mul(60)?),
+            Unit::Day => days(amount),
+            Unit::Week => days(amount.checked_mul(7)?),
+            Unit::Month => months(amount),
+            Unit::Year => months(amount.checked_mul(12)?),
+        }?;
+        Some((next, value))
+    }
+
+    /// Read the longest date or time expression at word `i`.
+    fn read(&self, i: usize) -> Option<(usize, TemporalValue)> {
+        let lexicon = self.lexicon;
+        let date_time = self.date(i).map(|(next, date)| {
+            let time = self.time(self.any(next, lexicon.at).unwrap_or(next));
+            match time {
+                Some((next, time)) => (next, TemporalValue::DateTime(date.and_time(time))),
+                None => (next, TemporalValue::Date(date)),
+            }
+        });
+        let time_date = self
+            .time(self.any(i, lexicon.at).unwrap_or(i))
+            .map(|(next, time)| {
+                let date = self.date(next);
+                match date {
+                    Some((next, date)) => (next, TemporalValue::DateTime(date.and_time(time))),
+                    None => (
+                        next,
+                        TemporalValue::DateTime(self.reference.date().and_time(time)),
+                    ),
+                }
+            });
+        [date_time, time_date, self.duration(i)]
+            .into_iter()
+            .flatten()
+            .max_by_key(|&(next, _)| next)
+    }
+}
+
+/// Find the date and time expressions in `text`, written in `lang` and tagged as `tagged`,
+/// resolving them against `reference`.
+pub(crate) fn find<'text>(
+    lang: Language,
+    text: &'text str,
+    tagged: impl IntoIterator<Item = (Position<usize>, Token<'text>, Option<Tag>)>,
+    reference: NaiveDateTime,
+) -> Vec<Temporal<'text>> {
+    let words = tagged
+        .into_iter()
+        .filter(|(_, token, _)| {
+            !matches!(
+                token,
+                Token::Separator(_) | Token::Whitespace(_) | Token::SeparatorOrWhitespace(_)
+            )
+        })
+        .map(|(position, token, tag)| {
+            let start = position.into_inner();
+            Word {
+                range: start..start + token.as_ref().len(),
+                token,
+                tag,
+            }
+        })
+        .collect();
+    let reader = Reader {
+        text,
+        words,
+        quantities: crate::number_words::find(text, lang),
+        lexicon: lexicon(lang),
+        reference,
+    };
+
+    let mut spanner = Spanner::new(text);
+    let mut found = Vec::new();
+    let mut i = 0;
+    while i < reader.words.len() {
+        let Some((next, value)) = reader.read(i) else {
+            i += 1;
+            continue;
+        };
+        let range = reader.words[i].range.start..reader.words[next - 1].range.end;
+        found.push(Temporal {
+            text: &text[range.clone()],
+            span: spanner.span(range),
+            value,
+        });
+        i = next;
+    }
+    found
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+    use crate::Tagger;
+
+    /// Saturday, 17 October 2026, at 10:00.
+    fn reference() -> NaiveDateTime {
+        NaiveDate::from_ymd_opt(2026, 10, 17)
+            .unwrap()
+            .and_hms_opt(10, 0, 0)
+            .unwrap()
+    }
+
+    fn date(year: i32, month: u32, day: u32) -> TemporalValue {
+        TemporalValue::Date(NaiveDate::from_ymd_opt(year, month, day).unwrap())
+    }
+
+    fn date_time(year: i32, month: u32, day: u32, hour: u32, minute: u32) -> TemporalValue {
+        let date = NaiveDate::from_ymd_opt(year, month, day).unwrap();
+        TemporalValue::DateTime(date.and_hms_opt(hour, minute, 0).unwrap())
+    }
+
+    fn temporals(lang: Language, text: &str) -> Vec<(&str, TemporalValue)> {
+        Tagger::new(lang)
+            .temporals(text, reference())
+            .map(|temporal| (temporal.text, temporal.value))
+            .collect()
+    }
+
+    #[cfg(feature = "english")]
+    #[test]
+    fn english() {
+        assert_eq!(
+            temporals(
+                Language::Eng,
+                "See you tomorrow at 5pm, or next Friday. It was due 2026-10-01 and on Oct. 3rd, 2025."
+            ),
+            [
+                ("tomorrow at 5pm", date_time(2026, 10, 18, 17, 0)),
+                ("next Friday", date(2026, 10, 23)),
+                ("2026-10-01", date(2026, 10, 1)),
+                ("Oct. 3rd, 2025", date(2025, 10, 3)),
+            ]
+        );
+        assert_eq!(
+            temporals(
+                Language::Eng,
+                "Call at 9:30 am, in three days, two weeks ago or 10/31/2026 last Saturday."
+            ),
+            [
+                ("at 9:30 am", date_time(2026, 10, 17, 9, 30)),
+                ("in three days", date(2026, 10, 20)),
+                ("two weeks ago", date(2026, 10, 3)),
+                ("10/31/2026", date(2026, 10, 31)),
+                ("last Saturday", date(2026, 10, 10)),
+            ]
+        );
+        assert_eq!(temporals(Language::Eng, "I may go, 5 of them at 3."), []);
+        assert_eq!(
+            temporals(Language::Eng, "We march 5 miles, may 3 friends come?"),
+            []
+        );
+        assert_eq!(
+            temporals(Language::Eng, "Back on May 3 or March 5."),
+            [("May 3", date(2026, 5, 3)), ("March 5", date(2026, 3, 5))]
+        );
+
+        let temporal = Tagger::new(Language::Eng)
+            .temporals("Ok, today", reference())
+            .next()
+            .unwrap();
+        assert_eq!(temporal.span.bytes(), 4..9);
+    }
+
+    #[cfg(all(
+        feature = "portuguese",
+        feature = "spanish",
+        feature = "french",
+        feature = "german"
+    ))]
+    #[test]
+    fn european() {
+        assert_eq!(
+            temporals(
+                Language::Por,
+                "Chego em 3 de março de 2027 às 17h30, ou sexta-feira que vem."
+            ),
+            [
+                ("3 de março de 2027 às 17h30", date_time(2027, 3, 3, 17, 30)),
+                ("sexta-feira que vem", date(2026, 10, 23)),
+            ]
+        );
+        assert_eq!(
+            temporals(
+                Language::Spa,
+                "Nos vemos el próximo viernes, hace dos días fue 17/10/2026."
+            ),
+            [
+                ("próximo viernes", date(2026, 10, 23)),
+                ("hace dos días", date(2026, 10, 15)),
+                ("17/10/2026", date(2026, 10, 17)),
+            ]
+        );
+        assert_eq!(
+            temporals(
+                Language::Fra,
+                "Rendez-vous demain à midi, pas il y a 2 heures."
+            ),
+            [
+                ("demain à midi", date_time(2026, 10, 18, 12, 0)),
+                ("il y a 2 heures", date_time(2026, 10, 17, 8, 0)),
+            ]
+        );
+        assert_eq!(
+            temporals(Language::Deu, "Am 3. März um 17 Uhr, oder übermorgen."),
+            [
+                ("3. März um 17 Uhr", date_time(2026, 3, 3, 17, 0)),
+                ("übermorgen", date(2026, 10, 19)),
+            ]
+        );
+        assert_eq!(
+            temporals(Language::Deu, "Guten Morgen, bis morgen!"),
+            [("morgen", date(2026, 10, 18))]
+        );
+    }
+
+    #[cfg(all(feature = "mandarin", feature = "russian"))]
+    #[test]
+    fn scripts() {
+        assert_eq!(
+            temporals(Language::Cmn, "我们明天下午5点见，2026年10月31日。"),
+            [
+                ("明天下午5点", date_time(2026, 10, 18, 17, 0)),
+                ("2026年10月31日", date(2026, 10, 31)),
+            ]
+        );
+        assert_eq!(
+            temporals(Language::Rus, "Увидимся через 2 дня или 5 марта."),
+            [
+                ("через 2 дня", date(2026, 10, 19)),
+                ("5 марта", date(2026, 3, 5))
+            ]
+        );
+    }
+}
@@ -0,0 +1,326 @@
+use std::borrow::Borrow;
+
+use itertools::{Either, Position};
+use unicode_categories::UnicodeCategories;
+
+use crate::{unicode_segmentation::punkt_token_positions, Language, Number, Punkt, Token};
+
+pub(crate) const APOSTROPHES: [char; 2] = ['\'', '’'];
+
+/// Tokenize text knowing the language it is written in.
+///
+/// On top of [`token_positions`](crate::token_positions), this applies rules of some languages:
+///
+/// - Sentences go on after the abbreviations of the language only, as in "Dr. Smith".
+/// - Chinese and Japanese text is split into the words of the dictionary ICU uses for both.
+/// - French and Italian elisions are split from their word, as in "l'" and "homme".
+/// - English contractions are split from their word, as in "do" and "n't".
+/// - Spanish inverted punctuation stands on its own, as in "¿" and "¡".
+/// - Hindi and Bengali words joined by virama and zero-width (non-)joiners stay letters.
+/// - Numbers are parsed with the decimal separator of the language, as in "1.000,50" in German.
+///
+/// German compounds, like any other word, are left intact.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Tokenizer {
+    pub lang: Language,
+}
+
+impl Tokenizer {
+    #[inline]
+    #[must_use]
+    pub const fn new(lang: Language) -> Self {
+        Self { lang }
+    }
+
+    /// Tokenize `text`, with positions like [`token_positions`](crate::token_positions).
+    #[inline]
+    pub fn token_positions<'text>(
+        &self,
+        text: &'text str,
+    ) -> impl Iterator<Item = (Position<usize>, Token<'text>)> + 'text {
+        self.punkt_token_positions(Punkt::for_language(self.lang), text)
+    }
+
+    /// Tokenize `text`, with sentence boundaries told by `punkt`.
+    pub(crate) fn punkt_token_positions<'punkt, 'text: 'punkt, P>(
+        &self,
+        punkt: P,
+        text: &'text str,
+    ) -> impl Iterator<Item = (Position<usize>, Token<'text>)> + 'punkt
+    where
+        P: Borrow<Punkt> + 'punkt,
+    {
+        let lang = self.lang;
+        punkt_token_positions(punkt, text).flat_map(move |(position, token)| {
+            let index = position.into_inner();
+            let word = &text[index..index + token.as_ref().len()];
+            match split(lang, word).filter(|words| words.len() > 1) {
+                Some(words) => Either::Right(positioned(position, words)),
+                None => Either::Left(std::iter::once((position, retag(lang, token, word)))),
+            }
+        })
+    }
+}
+
+/// Split `word` following the rules of `lang`, if any applies.
+fn split(lang: Language, word: &str) -> Option<Vec<&str>> {
+    crate::segmentation::segment(lang, word)
+        .or_else(|| split_elision(lang, word))
+        .or_else(|| split_contraction(lang, word))
+        .or_else(|| split_inverted(lang, word))
+}
+
+/// Give positions to the parts of a token at `position`.
+fn positioned<'text>(
+    position: Position<usize>,
+    words: Vec<&'text str>,
+) -> impl Iterator<Item = (Position<usize>, Token<'text>)> {
+    use Position::{First, Last, Middle, Only};
+
+    let last = words.len() - 1;
+    let mut start = position.into_inner();
+    words.into_iter().enumerate().map(move |(n, word)| {
+        let index = start;
+        start += word.len();
+        let first = n == 0 && matches!(position, First(_) | Only(_));
+        let last = n == last && matches!(position, Last(_) | Only(_));
+        let position = match (first, last) {
+            (true, true) => Only(index),
+            (true, false) => First(index),
+            (false, true) => Last(index),
+            (false, false) => Middle(index),
+        };
+        (position, Token::from(word))
+    })
+}
+
+/// Words that drop their final vowel before another word, as in "l'homme".
+const fn elisions(lang: Language) -> &'static [&'static str] {
+    match lang {
+        #[cfg(feature = "french")]
+        Language::Fra => &[
+            "c", "d", "j", "jusqu", "l", "lorsqu", "m", "n", "puisqu", "qu", "quoiqu", "s", "t",
+        ],
+        #[cfg(feature = "italian")]
+        Language::Ita => &[
+            "all", "c", "d", "dall", "dell", "l", "nell", "quell", "quest", "sull", "un",
+        ],
+        #[allow(unreachable_patterns)]
+        _ => &[],
+    }
+}
+
+/// Split an elided word from the word it precedes, as in "l'" and "homme".
+fn split_elision(lang: Language, word: &str) -> Option<Vec<&str>> {
+    let index = word.find(APOSTROPHES)?;
+    let (head, tail) = word.split_at(index);
+    let apostrophe = tail.chars().next()?.len_utf8();
+    let elided = elisions(lang).contains(&head.to_lowercase().as_str());
+    (elided && tail.len() > apostrophe).then(|| {
+        let (head, tail) = word.split_at(index + apostrophe);
+        vec![head, tail]
+    })
+}
+
+/// Split a clitic from the word it is attached to, as in "do" and "n't", or "I" and "'m".
+fn split_contraction(lang: Language, word: &str) -> Option<Vec<&str>> {
+    #[cfg(feature = "english")]
+    if lang == Language::Eng {
+        let lowercase = word.to_lowercase().replace('’', "'");
+        let clitic = ["n't", "'s", "'m", "'re", "'ve", "'ll", "'d"]
+            .into_iter()
+            .find(|clitic| lowercase.ends_with(clitic))?;
+        // NOTE: "’" is longer than "'" in bytes
+        let len: usize = word
+            .chars()
+            .rev()
+            .take(clitic.len())
+            .map(char::len_utf8)
+            .sum();
+        let (head, tail) = word.split_at(word.len() - len);
+        return (!head.is_empty() && head.chars().all(char::is_alphabetic))
+            .then(|| vec![head, tail]);
+    }
+    let _ = (lang, word);
+    None
+}
+
+/// Keep Spanish inverted question and exclamation marks on their own, as in "¡¿".
+fn split_inverted(lang: Language, word: &str) -> Option<Vec<&str>> {
+    #[cfg(feature = "spanish")]
+    if lang == Language::Spa {
+        const INVERTED: [char; 2] = ['¿', '¡'];
+
+        if !word.contains(INVERTED) || !word.chars().all(char::is_punctuation) {
+            return None;
+        }
+        let mut words = Vec::new();
+        let mut start = 0;
+        for (index, c) in word.char_indices() {
+            if INVERTED.contains(&c) {
+                words.extend([&word[start..index], &word[index..index + c.len_utf8()]]);
+                start = index + c.len_utf8();
+            }
+        }
+        words.push(&word[start..]);
+        words.retain(|word| !word.is_empty());
+        return Some(words);
+    }
+    let _ = (lang, word);
+    None
+}
+
+/// Parse numbers with the decimal separator of `lang`, leaving those that don't follow it
+/// without a value, and classify Hindi and Bengali words
+/// holding zero-width (non-)joiners as letters,
+/// as those only select how conjuncts around a virama are rendered.
+fn retag<'text>(lang: Language, token: Token<'text>, word: &'text str) -> Token<'text> {
+    if let Token::Number(number) = token {
+        // NOTE: a value guessed without the language may be wrong, as "1.000,5" in English
+        let parsed = Number::parse(word, Some(lang)).unwrap_or(Number::other(number.text));
+        return Token::Number(parsed);
+    }
+    let indic = match lang {
+        #[cfg(feature = "hindi")]
+        Language::Hin => true,
+        #[cfg(feature = "bengali")]
+        Language::Ben => true,
+        #[allow(unreachable_patterns)]
+        _ => false,
+    };
+    let joined = matches!(token, Token::Mixed(_) | Token::LetterOrOther(_))
+        && word
+            .chars()
+            .all(|c| c.is_letter() || c.is_mark() || matches!(c, '\u{200c}' | '\u{200d}'));
+    if indic && joined {
+        Token::LetterOrMark(word)
+    } else {
+        token
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+
+    fn words(lang: Language, text: &str) -> Vec<String> {
+        Tokenizer::new(lang)
+            .token_positions(text)
+            .map(|(_, token)| token.as_ref().to_owned())
+            .collect()
+    }
+
+    #[cfg(all(feature = "french", feature = "italian", feature = "spanish"))]
+    #[test]
+    fn split_elisions() {
+        assert_eq!(
+            words(Language::Fra, "L'homme qu’il voit aujourd'hui"),
+            [
+                "L'",
+                "homme",
+                " ",
+                "qu’",
+                "il",
+                " ",
+                "voit",
+                " ",
+                "aujourd'hui"
+            ]
+        );
+        assert_eq!(words(Language::Ita, "dell'anno"), ["dell'", "anno"]);
+        // NOTE: no elision is split in other languages
+        assert_eq!(words(Language::Spa, "l'homme"), ["l'homme"]);
+    }
+
+    #[cfg(feature = "english")]
+    #[test]
+    fn contractions() {
+        use Position::{First, Last, Middle};
+        use Token::{Letter, LetterOrPunctuation, Punctuation};
+
+        let tokens: Vec<_> = Tokenizer::new(Language::Eng)
+            .token_positions("Don't")
+            .collect();
+        assert_eq!(
+            tokens,
+            [
+                (First(0), Letter("Do")),
+                (Last(2), LetterOrPunctuation("n't")),
+            ]
+        );
+        assert_eq!(
+            words(Language::Eng, "I’m sure it's John's, o'clock."),
+            [
+                "I", "’m", " ", "sure", " ", "it", "'s", " ", "John", "'s", ",", " ", "o'clock",
+                "."
+            ]
+        );
+        let tokens: Vec<_> = Tokenizer::new(Language::Eng)
+            .token_positions("we can't.")
+            .collect();
+        assert_eq!(
+            tokens[2..],
+            [
+                (Middle(3), Letter("ca")),
+                (Middle(5), LetterOrPunctuation("n't")),
+                (Last(8), Punctuation("."))
+            ]
+        );
+    }
+
+    #[cfg(all(feature = "german", feature = "spanish"))]
+    #[test]
+    fn compounds_and_inverted_punctuation() {
+        assert_eq!(
+            words(Language::Deu, "Donaudampfschifffahrt"),
+            ["Donaudampfschifffahrt"]
+        );
+        assert_eq!(words(Language::Spa, "¿¡Qué!?"), ["¿", "¡", "Qué", "!?"]);
+    }
+
+    #[cfg(all(feature = "english", feature = "german"))]
+    #[test]
+    fn numbers() {
+        let values = |lang, text| -> Vec<_> {
+            Tokenizer::new(lang)
+                .token_positions(text)
+                .filter_map(|(_, token)| match token {
+                    Token::Number(number) => number.value,
+                    _ => None,
+                })
+                .collect()
+        };
+        assert_eq!(values(Language::Deu, "1.000,50 und 1,5"), [1000.5, 1.5]);
+        assert_eq!(values(Language::Eng, "1,000.50 and 1,5"), [1000.5]);
+        assert_eq!(values(Language::Eng, "1,000 and 1.000"), [1000.0, 1.0]);
+        assert_eq!(values(Language::Deu, "1,000 und 1.000"), [1.0, 1000.0]);
+
+        let tokens: Vec<_> = Tokenizer::new(Language::Eng)
+            .token_positions("1.000,5")
+            .map(|(_, token)| token)
+            .collect();
+        assert_eq!(tokens, [Token::Number(Number::other("1.000,5"))]);
+    }
+
+    #[cfg(all(feature = "hindi", feature = "mandarin"))]
+    #[test]
+    fn scripts() {
+        // NOTE: a zero-width non-joiner after a virama, to show it explicitly
+        let word = "क्\u{200c}ष";
+        assert_eq!(Token::from(word), Token::Mixed(word));
+        let tokens: Vec<_> = Tokenizer::new(Language::Hin)
+            .token_positions(word)
+            .map(|(_, token)| token)
+            .collect();
+        assert_eq!(tokens, [Token::LetterOrMark(word)]);
+
+        use Position::{First, Last, Middle};
+        let positions: Vec<_> = Tokenizer::new(Language::Cmn)
+            .token_positions("我们是学生")
+            .map(|(position, _)| position)
+            .collect();
+        assert_eq!(positions, [First(0), Middle(6), Last(9)]);
+    }
+}
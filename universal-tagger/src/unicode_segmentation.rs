@@ -1,14 +1,21 @@
+use std::borrow::Borrow;
+
 use itertools::{Itertools, Position};
 
+use crate::{Number, Punkt};
+
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum Token<'text> {
     Separator(&'text str),
     Whitespace(&'text str),
     SeparatorOrWhitespace(&'text str),
     Letter(&'text str),
     Punctuation(&'text str),
-    Number(&'text str),
-    Float(&'text str),
+    /// A number, with its value when it has one, such as `1,000.50`, `-3%` or `2nd`.
+    ///
+    /// This replaces `Token::Float`: numbers with a fraction are of [`NumberKind::Decimal`](crate::NumberKind::Decimal).
+    Number(#[cfg_attr(feature = "serde", serde(borrow))] Number<'text>),
     Symbol(&'text str),
     Mark(&'text str),
     LetterOrPunctuation(&'text str),
@@ -20,6 +27,25 @@ pub enum Token<'text> {
     LetterOrOther(&'text str),
     NumberOrOther(&'text str),
     OtherOrPunctuation(&'text str),
+    /// Any other mix of categories, such as emoji sequences joining symbols with
+    /// format characters or variation selectors.
+    Mixed(&'text str),
+    /// A URL, such as `https://example.com/a?b=c` or `www.example.com`.
+    Url(&'text str),
+    /// An email address, such as `user@example.com`.
+    Email(&'text str),
+    /// A mention of a user, such as `@user`.
+    Mention(&'text str),
+    /// A hashtag, such as `#rust`.
+    Hashtag(&'text str),
+    /// An IPv4 or IPv6 address, possibly with a port, such as `127.0.0.1:8080`.
+    IpAddress(&'text str),
+    /// A file path, such as `/etc/hosts`, `./notes.txt` or `C:\Users`.
+    Path(&'text str),
+    /// A whole emoji sequence, such as `👍🏽`, `🇧🇷`, `1️⃣` or `👩‍💻`.
+    ///
+    /// See [`Token::emoji`] for its name and group.
+    Emoji(&'text str),
 }
 
 impl<'text> From<&'text str> for Token<'text> {
@@ -27,6 +53,13 @@ impl<'text> From<&'text str> for Token<'text> {
     fn from(word: &'text str) -> Self {
         use unicode_categories::UnicodeCategories;
 
+        if let Some(entity) = crate::entity::classify(word) {
+            return entity;
+        }
+        if emojis::get(word).is_some_and(|emoji| has_emoji_presentation(word, emoji)) {
+            return Self::Emoji(word);
+        }
+
         match word {
             word if word.chars().all(char::is_separator) => Self::Separator(word),
             word if word.chars().all(|c| c.is_whitespace() && !c.is_separator()) => {
@@ -39,8 +72,7 @@ impl<'text> From<&'text str> for Token<'text> {
             word if word.chars().all(char::is_letter) => Self::Letter(word),
             word if word.chars().all(char::is_punctuation) => Self::Punctuation(word),
             // TODO: do we need numeric?
-            word if word.chars().all(char::is_number) => Self::Number(word),
-            word if word.parse::<f64>().is_ok() => Self::Float(word),
+            word if word.chars().all(char::is_number) => Self::Number(Number::other(word)),
             word if word.chars().all(char::is_symbol) => Self::Symbol(word),
             word if word.chars().all(char::is_mark) => Self::Mark(word),
             word if word.chars().all(|c| c.is_letter() || c.is_punctuation()) => {
@@ -65,7 +97,7 @@ impl<'text> From<&'text str> for Token<'text> {
             word if word.chars().all(|c| c.is_punctuation() || c.is_other()) => {
                 Self::OtherOrPunctuation(word)
             }
-            word => unreachable!("possibly missing case: {word:?}"),
+            word => Self::Mixed(word),
         }
     }
 }
@@ -74,9 +106,10 @@ impl<'text> AsRef<str> for Token<'text> {
     #[inline]
     fn as_ref(&self) -> &str {
         use Token::{
-            Float, Letter, LetterOrMark, LetterOrNumber, LetterOrOther, LetterOrPunctuation, Mark,
-            Number, NumberOrMark, NumberOrOther, NumberOrPunctuation, Other, OtherOrPunctuation,
-            Punctuation, Separator, SeparatorOrWhitespace, Symbol, Whitespace,
+            Email, Emoji, Hashtag, IpAddress, Letter, LetterOrMark, LetterOrNumber, LetterOrOther,
+            LetterOrPunctuation, Mark, Mention, Mixed, Number, NumberOrMark, NumberOrOther,
+            NumberOrPunctuation, Other, OtherOrPunctuation, Path, Punctuation, Separator,
+            SeparatorOrWhitespace, Symbol, Url, Whitespace,
         };
 
         match self {
@@ -85,8 +118,6 @@ impl<'text> AsRef<str> for Token<'text> {
             | SeparatorOrWhitespace(word)
             | Letter(word)
             | Punctuation(word)
-            | Number(word)
-            | Float(word)
             | Symbol(word)
             | Mark(word)
             | LetterOrPunctuation(word)
@@ -97,16 +128,51 @@ impl<'text> AsRef<str> for Token<'text> {
             | Other(word)
             | LetterOrOther(word)
             | NumberOrOther(word)
-            | OtherOrPunctuation(word) => word,
+            | OtherOrPunctuation(word)
+            | Mixed(word)
+            | Url(word)
+            | Email(word)
+            | Mention(word)
+            | Hashtag(word)
+            | IpAddress(word)
+            | Path(word)
+            | Emoji(word) => word,
+            Number(number) => number.text,
         }
     }
 }
 
+/// Whether `word`, an `emoji`, is displayed as one rather than as text, as "❤️" but not "❤".
+///
+/// That is when it is fully qualified, holds a variation selector-16,
+/// or holds a code point with the `Emoji_Presentation` property, as "😀".
+fn has_emoji_presentation(word: &str, emoji: &emojis::Emoji) -> bool {
+    emoji.as_str() == word
+        || word.contains('\u{fe0f}')
+        || word.chars().any(|c| {
+            // NOTE: code points shown as text by default are only fully qualified with VS16
+            emojis::get(c.encode_utf8(&mut [0; 4]))
+                .is_some_and(|emoji| emoji.as_str().len() == c.len_utf8())
+        })
+}
+
 impl<'text> Token<'text> {
+    /// The emoji of an [`Token::Emoji`], with its CLDR short name, group and skin tone.
+    ///
+    /// Unqualified and minimally qualified emoji give their fully qualified version.
+    #[inline]
+    #[must_use]
+    pub fn emoji(&self) -> Option<&'static emojis::Emoji> {
+        match self {
+            Self::Emoji(emoji) => emojis::get(emoji),
+            _ => None,
+        }
+    }
+
     #[inline]
     const fn can_merge(first: &Self, second: &Self) -> bool {
         use Token::{
-            Float, Letter, LetterOrMark, LetterOrNumber, LetterOrOther, LetterOrPunctuation, Mark,
+            Letter, LetterOrMark, LetterOrNumber, LetterOrOther, LetterOrPunctuation, Mark, Mixed,
             Number, NumberOrMark, NumberOrOther, NumberOrPunctuation, Other, OtherOrPunctuation,
             Punctuation, Separator, SeparatorOrWhitespace, Symbol, Whitespace,
         };
@@ -119,7 +185,6 @@ impl<'text> Token<'text> {
             ) | (Letter(_), Letter(_))
                 | (Punctuation(_), Punctuation(_))
                 | (Number(_), Number(_))
-                | (Float(_), Float(_))
                 | (Symbol(_), Symbol(_))
                 | (Mark(_), Mark(_))
                 | (LetterOrPunctuation(_), LetterOrPunctuation(_))
@@ -131,42 +196,19 @@ impl<'text> Token<'text> {
                 | (LetterOrOther(_), LetterOrOther(_))
                 | (NumberOrOther(_), NumberOrOther(_))
                 | (OtherOrPunctuation(_), OtherOrPunctuation(_))
+                | (Mixed(_), Mixed(_))
         )
     }
-
-    /// Coalesce two tokens.
-    ///
-    /// # Safety
-    ///
-    /// The pointers have to be from the same string and be next to each other
-    /// in the original string.
-    #[inline]
-    unsafe fn coalesce_with(self, other: Self) -> Self {
-        use std::{slice, str};
-
-        let (first, second) = (self.as_ref(), other.as_ref());
-
-        let ptr = first.as_ptr();
-        let len = first.len() + second.len();
-
-        let utf8 = unsafe {
-            let slice = slice::from_raw_parts(ptr, len);
-            str::from_utf8_unchecked(slice)
-        };
-
-        utf8.into()
-    }
 }
 
-/// Coalesce adjacent tokens.
-///
-/// # Safety
+/// Coalesce adjacent tokens of `text`, joining their byte ranges.
 ///
-/// The whole iterator should yield pointers from the same string,
-/// and adjacent yielded items should be next to each other
-/// in the original string.
+/// Runs of tokens of the same kind are joined first, and only then classified as a whole.
+// NOTE: `coalesce` gives back both tokens, with where their runs end, when they can't be merged
+#[allow(clippy::result_large_err)]
 #[inline]
-unsafe fn coalesce_tokens<'text, I>(
+fn coalesce_tokens<'text, I>(
+    text: &'text str,
     iter: I,
 ) -> impl Iterator<Item = (Position<usize>, Token<'text>)>
 where
@@ -174,31 +216,36 @@ where
 {
     use Position::{First, Last, Middle, Only};
 
-    iter.coalesce(
-        |fst @ (first_position, first), snd @ (second_position, second)| match (
+    // NOTE: a run keeps its first token, which tells its kind, along with where the run ends
+    iter.map(|(position, token)| {
+        let end = position.into_inner() + token.as_ref().len();
+        (position, token, end)
+    })
+    .coalesce(
+        |fst @ (first_position, first, _), snd @ (second_position, second, end)| match (
             first_position,
             second_position,
         ) {
             (First(first_index), Last(_)) if Token::can_merge(&first, &second) => {
-                Ok((Only(first_index), unsafe { first.coalesce_with(second) }))
+                Ok((Only(first_index), first, end))
             }
             (First(first_index), Middle(_)) if Token::can_merge(&first, &second) => {
-                Ok((First(first_index), unsafe { first.coalesce_with(second) }))
+                Ok((First(first_index), first, end))
             }
             (Last(first_index), Only(_)) if Token::can_merge(&first, &second) => {
-                Ok((Last(first_index), unsafe { first.coalesce_with(second) }))
+                Ok((Last(first_index), first, end))
             }
             (Middle(first_index), Last(_)) if Token::can_merge(&first, &second) => {
-                Ok((Last(first_index), unsafe { first.coalesce_with(second) }))
+                Ok((Last(first_index), first, end))
             }
             (Middle(first_index), Middle(_)) if Token::can_merge(&first, &second) => {
-                Ok((Middle(first_index), unsafe { first.coalesce_with(second) }))
+                Ok((Middle(first_index), first, end))
             }
             (Only(first_index), First(_)) if Token::can_merge(&first, &second) => {
-                Ok((First(first_index), unsafe { first.coalesce_with(second) }))
+                Ok((First(first_index), first, end))
             }
             (Only(first_index), Only(_)) if Token::can_merge(&first, &second) => {
-                Ok((Only(first_index), unsafe { first.coalesce_with(second) }))
+                Ok((Only(first_index), first, end))
             }
             (First(_) | Middle(_), First(_) | Only(_))
             | (Last(_) | Only(_), Last(_) | Middle(_)) => {
@@ -207,16 +254,33 @@ where
             _ => Err((fst, snd)),
         },
     )
+    .map(move |(position, token, end)| {
+        let start = position.into_inner();
+        if start + token.as_ref().len() == end {
+            (position, token)
+        } else {
+            (position, Token::from(&text[start..end]))
+        }
+    })
 }
 
 #[inline]
-pub fn token_positions(text: &str) -> impl Iterator<Item = (Position<usize>, Token)> {
+pub fn token_positions(text: &str) -> impl Iterator<Item = (Position<usize>, Token<'_>)> {
+    punkt_token_positions(&crate::punkt::DEFAULT, text)
+}
+
+/// Tokenize `text` like [`token_positions`], with sentence boundaries told by `punkt`.
+pub(crate) fn punkt_token_positions<'punkt, 'text: 'punkt, P>(
+    punkt: P,
+    text: &'text str,
+) -> impl Iterator<Item = (Position<usize>, Token<'text>)> + 'punkt
+where
+    P: Borrow<Punkt> + 'punkt,
+{
     use Position::{First, Last, Middle, Only};
     use Token::{Separator, SeparatorOrWhitespace, Whitespace};
 
-    let iter = isolated_token_positions(text);
-    // SAFETY: iter yields from the same string and items are adjacent.
-    let iter = unsafe { coalesce_tokens(iter) };
+    let iter = coalesce_tokens(text, isolated_token_positions(punkt, text));
     let iter = iter.coalesce(|fst, snd| match (fst, snd) {
         (
             (First(first_index), first),
@@ -237,40 +301,230 @@ pub fn token_positions(text: &str) -> impl Iterator<Item = (Position<usize>, Tok
         _ => Err((fst, snd)),
     });
 
-    // SAFETY: iter yields from the same string and items are adjacent.
-    unsafe { coalesce_tokens(iter) }
+    coalesce_tokens(text, iter)
 }
 
 #[inline]
-fn word_positions(text: &str) -> impl Iterator<Item = (Position<usize>, &str)> {
-    use unicode_segmentation::UnicodeSegmentation;
+fn word_positions<'punkt, 'text: 'punkt, P>(
+    punkt: P,
+    text: &'text str,
+) -> impl Iterator<Item = (Position<usize>, &'text str)> + 'punkt
+where
+    P: Borrow<Punkt> + 'punkt,
+{
     use Position::{First, Last, Middle, Only};
 
-    text.split_sentence_bound_indices()
-        .flat_map(|(start, sentence)| {
-            sentence
-                .split_word_bound_indices()
-                .with_position()
-                .map(move |item| match item {
-                    First((index, word)) => (First(start + index), word),
-                    Middle((index, word)) => (Middle(start + index), word),
-                    Last((index, word)) => (Last(start + index), word),
-                    Only((index, word)) => (Only(start + index), word),
-                })
-        })
+    crate::punkt::bound_indices(punkt, text).flat_map(|(start, sentence)| {
+        split_word_bound_indices(sentence)
+            .with_position()
+            .map(move |item| match item {
+                First((index, word)) => (First(start + index), word),
+                Middle((index, word)) => (Middle(start + index), word),
+                Last((index, word)) => (Last(start + index), word),
+                Only((index, word)) => (Only(start + index), word),
+            })
+    })
+}
+
+/// Split `text` at word boundaries, keeping entities such as URLs as single words.
+fn split_word_bound_indices(text: &str) -> impl Iterator<Item = (usize, &str)> {
+    use unicode_segmentation::UnicodeSegmentation;
+
+    let mut entities = crate::entity::find(text).peekable();
+    let mut start = 0;
+    std::iter::from_fn(move || {
+        if start == text.len() {
+            return None;
+        }
+        let end = entities.peek().map_or(text.len(), |entity| entity.start);
+        let words = text[start..end]
+            .split_word_bound_indices()
+            .map(move |(index, word)| (start + index, word));
+        let entity = entities
+            .next()
+            .map(|entity| (entity.start, &text[entity.clone()]));
+        start = entity.map_or(text.len(), |(index, word)| index + word.len());
+        Some(words.chain(entity))
+    })
+    .flatten()
 }
 
 #[inline]
-fn isolated_token_positions(text: &str) -> impl Iterator<Item = (Position<usize>, Token)> {
-    word_positions(text).map(|(position, word)| (position, Token::from(word)))
+fn isolated_token_positions<'punkt, 'text: 'punkt, P>(
+    punkt: P,
+    text: &'text str,
+) -> impl Iterator<Item = (Position<usize>, Token<'text>)> + 'punkt
+where
+    P: Borrow<Punkt> + 'punkt,
+{
+    word_positions(punkt, text).map(|(position, word)| (position, Token::from(word)))
 }
 
 #[cfg(test)]
 mod tests {
     use pretty_assertions::assert_eq;
+    use proptest::prelude::*;
 
     use super::*;
 
+    /// Check that the tokens of `text` cover it exactly, in order.
+    fn assert_covers(text: &str) {
+        let mut end = 0;
+        for (position, token) in token_positions(text) {
+            assert_eq!(position.into_inner(), end, "in {text:?}");
+            assert!(!token.as_ref().is_empty(), "in {text:?}");
+            end += token.as_ref().len();
+        }
+        assert_eq!(end, text.len(), "in {text:?}");
+    }
+
+    proptest! {
+        #[test]
+        fn classify_any_word(word in any::<String>()) {
+            let token = Token::from(word.as_str());
+            prop_assert_eq!(token.as_ref(), word.as_str());
+        }
+
+        #[test]
+        fn tokenize_any_text(text in any::<String>()) {
+            assert_covers(&text);
+        }
+
+        #[test]
+        fn tokenize_any_printable_text(text in "\\PC*") {
+            assert_covers(&text);
+        }
+    }
+
+    #[test]
+    fn tokenize_emoji() {
+        let data = include_str!("../tests/emoji-test.txt");
+        let emoji: Vec<(String, &str, &str)> = data
+            .lines()
+            .filter_map(|line| {
+                let (code_points, rest) = line.split_once(';')?;
+                let (status, comment) = rest.split_once('#')?;
+                Some((code_points, status.trim(), comment))
+            })
+            .filter(|(code_points, _, _)| !code_points.starts_with('#'))
+            .map(|(code_points, status, comment)| {
+                let emoji = code_points
+                    .split_whitespace()
+                    .map(|code_point| {
+                        let code_point = u32::from_str_radix(code_point, 16).unwrap();
+                        char::from_u32(code_point).unwrap()
+                    })
+                    .collect();
+                // NOTE: comments look like "😀 E1.0 grinning face"
+                let name = comment.trim().splitn(3, ' ').nth(2).unwrap_or_default();
+                (emoji, status, name)
+            })
+            .collect();
+        assert!(emoji.len() > 4000);
+
+        for (emoji, status, name) in &emoji {
+            assert_covers(emoji);
+            assert_covers(&format!("I {emoji} you. {emoji}{emoji}, ok?"));
+            if *status == "fully-qualified" {
+                let tokens: Vec<_> = token_positions(emoji).map(|(_, token)| token).collect();
+                assert_eq!(tokens, [Token::Emoji(emoji)]);
+                // NOTE: names may be capitalized differently across Unicode versions
+                let found = tokens[0].emoji().unwrap().name();
+                assert_eq!(found.to_lowercase(), name.to_lowercase());
+            }
+        }
+    }
+
+    #[test]
+    fn emoji_sequences() {
+        use Token::{Emoji, Letter, Separator};
+
+        let text = "so 👩‍👩‍👧🇧🇷👍🏽1️⃣";
+        let tokens: Vec<_> = token_positions(text).map(|(_, token)| token).collect();
+        assert_eq!(
+            tokens,
+            [
+                Letter("so"),
+                Separator(" "),
+                Emoji("👩‍👩‍👧"),
+                Emoji("🇧🇷"),
+                Emoji("👍🏽"),
+                Emoji("1️⃣"),
+            ]
+        );
+
+        let thumbs = tokens[4].emoji().unwrap();
+        assert_eq!(thumbs.name(), "thumbs up: medium skin tone");
+        assert_eq!(thumbs.group(), emojis::Group::PeopleAndBody);
+        assert_eq!(thumbs.skin_tone(), Some(emojis::SkinTone::Medium));
+        assert_eq!(
+            Token::from("\u{263a}\u{fe0f}").emoji().unwrap().as_str(),
+            "☺️"
+        );
+        assert_eq!(Letter("so").emoji(), None);
+
+        // NOTE: these are shown as text unless followed by a variation selector-16
+        for text in ["©", "™", "‼", "↔", "❤", "\u{263a}"] {
+            assert!(!matches!(Token::from(text), Emoji(_)), "{text}");
+            let emoji = format!("{text}\u{fe0f}");
+            assert_eq!(Token::from(emoji.as_str()), Emoji(&emoji));
+        }
+        assert_eq!(Token::from("🏳\u{200d}🌈"), Emoji("🏳\u{200d}🌈"));
+    }
+
+    #[test]
+    fn tokenize_entities() {
+        use Position::{First, Last, Middle, Only};
+        use Token::{Email, Letter, Mention, Punctuation, Separator, Url};
+
+        let text = "@ana: see https://example.com/a?b=c! Mail user@example.com.";
+        let tokens: Vec<_> = token_positions(text).collect();
+        assert_eq!(
+            tokens,
+            &[
+                (First(0), Mention("@ana")),
+                (Middle(4), Punctuation(":")),
+                (Middle(5), Separator(" ")),
+                (Middle(6), Letter("see")),
+                (Middle(9), Separator(" ")),
+                (Middle(10), Url("https://example.com/a?b=c")),
+                (Last(35), Punctuation("!")),
+                (Only(36), Separator(" ")),
+                (First(37), Letter("Mail")),
+                (Middle(41), Separator(" ")),
+                (Middle(42), Email("user@example.com")),
+                (Last(58), Punctuation(".")),
+            ]
+        );
+        assert_covers("a http://x.io?  b #tag#tag @@x /a/ [::1] ~/");
+    }
+
+    #[test]
+    fn tokenize_numbers() {
+        use crate::NumberKind::{Decimal, Ordinal, Other, Percentage};
+
+        let numbers: Vec<_> = token_positions("Up -1,5% on the 2nd, ½ of १२.५ 1️⃣")
+            .filter_map(|(_, token)| match token {
+                Token::Number(number) => Some((number.text, number.value, number.kind)),
+                _ => None,
+            })
+            .collect();
+        assert_eq!(
+            numbers,
+            [
+                ("-1,5%", Some(-1.5), Percentage),
+                ("2nd", Some(2.0), Ordinal),
+                ("½", None, Other),
+                ("१२.५", Some(12.5), Decimal),
+            ]
+        );
+        assert_covers("1\u{a0}000 -x 1e 2e5 3.14.15 +");
+
+        // NOTE: a run of numbers is classified as a whole, even when it is no number as a whole
+        let tokens: Vec<_> = token_positions("0.0½½").map(|(_, token)| token).collect();
+        assert_eq!(tokens, [Token::NumberOrPunctuation("0.0½½")]);
+    }
+
     #[test]
     fn token_usage() {
         use Position::{First, Last, Middle};